@@ -124,7 +124,7 @@ fn bench_le_param_gamma_varying_k(c: &mut Criterion) {
 }
 
 //
-// BEIntVec benchmarks (assuming BEIntVec has a similar `get()` method)
+// BEIntVec benchmarks
 //
 
 fn bench_be_gamma_varying_k(c: &mut Criterion) {
@@ -0,0 +1,161 @@
+//! # Asymmetric Numeral System (rANS) block codec
+//!
+//! This module implements a static, table-driven rANS coder: given a frequency
+//! table trained over a block of symbols, it encodes the whole block in one
+//! pass and decodes it back in original order.
+//!
+//! ## Why this isn't a [`Codec`](crate::codecs::Codec)
+//!
+//! Every codec in [`codecs`](crate::codecs) implements encode/decode for a
+//! single value at a time, which is what lets [`IntVec`](crate::intvec::IntVec)
+//! interleave encoding with sample bookkeeping in `from_with_param`. rANS
+//! cannot fit that shape: it is a LIFO coder, so a whole block must be known
+//! up front to build the frequency table, and symbols are pushed onto the
+//! encoder state in reverse order before the resulting byte stream can be read
+//! forward by the decoder. Wiring this into `IntVec` would mean reworking its
+//! per-value encode loop into a block-oriented one (resetting the rANS state
+//! at every sampling boundary and storing the flushed state alongside each
+//! sample), which is a separate, larger change. This module provides the
+//! block primitives — frequency training, `encode_block`, `decode_block` — as
+//! a standalone building block for that future integration.
+//!
+//! ## Algorithm
+//!
+//! Frequencies are quantized so they sum to `M = 2^precision`, with every
+//! symbol that appears at least once guaranteed a frequency of at least 1.
+//! Encoding walks the block in reverse, renormalizing the `u32` state by
+//! emitting low bytes whenever `x >= ((ANS_L >> precision) << 8) * freq(s)`,
+//! then folding in the symbol via
+//! `x = (x / freq(s) << precision) + (x % freq(s)) + cum(s)`. Decoding walks
+//! forward from the flushed final state, recovering the symbol from
+//! `slot = x & (M - 1)` via the cumulative table, updating
+//! `x = freq(s) * (x >> precision) + slot - cum(s)`, and renormalizing by
+//! pulling bytes back in whenever `x` drops below `ANS_L`.
+
+/// Number of bits of precision for the quantized frequency table; the total
+/// of all frequencies is always `1 << ANS_PRECISION`.
+pub const ANS_PRECISION: u32 = 12;
+
+/// Lower renormalization bound for the encoder/decoder state.
+const ANS_L: u32 = 1 << 23;
+
+/// A quantized frequency table trained over a block of symbols.
+///
+/// `freq[s]` and `cum[s]` give the frequency and cumulative frequency of
+/// symbol `s`; `cum` has one extra trailing entry equal to `1 << ANS_PRECISION`.
+#[derive(Debug, Clone)]
+pub struct AnsModel {
+    pub freq: Vec<u32>,
+    pub cum: Vec<u32>,
+}
+
+impl AnsModel {
+    /// Trains a quantized frequency table over `symbols`, whose values must
+    /// all be `< alphabet_size`.
+    ///
+    /// Counts are rescaled so they sum exactly to `1 << ANS_PRECISION`,
+    /// without ever letting a symbol that actually occurs end up with a
+    /// frequency of zero (which would make it unencodable). When more than
+    /// `1 << ANS_PRECISION` distinct symbols occur, every present symbol keeps
+    /// a frequency of 1 and the total exceeds `1 << ANS_PRECISION`.
+    pub fn train(symbols: &[u64], alphabet_size: usize) -> Self {
+        let m = 1u32 << ANS_PRECISION;
+        let mut counts = vec![0u64; alphabet_size];
+        for &s in symbols {
+            counts[s as usize] += 1;
+        }
+        let total: u64 = counts.iter().sum();
+
+        let mut freq = vec![0u32; alphabet_size];
+        if total > 0 {
+            for (f, &c) in freq.iter_mut().zip(counts.iter()) {
+                if c > 0 {
+                    *f = (((c * m as u64) / total) as u32).max(1);
+                }
+            }
+            let mut diff = m as i64 - freq.iter().map(|&f| f as i64).sum::<i64>();
+            while diff != 0 {
+                let idx = freq
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| counts[i] > 0)
+                    .max_by_key(|&(_, &f)| f)
+                    .map(|(i, _)| i)
+                    .expect("total > 0 implies at least one present symbol");
+                if diff > 0 {
+                    freq[idx] += 1;
+                    diff -= 1;
+                } else if freq[idx] > 1 {
+                    freq[idx] -= 1;
+                    diff += 1;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        let mut cum = vec![0u32; alphabet_size + 1];
+        for i in 0..alphabet_size {
+            cum[i + 1] = cum[i] + freq[i];
+        }
+        AnsModel { freq, cum }
+    }
+
+    /// Finds the symbol whose cumulative-frequency range contains `slot` via
+    /// binary search over the monotone `cum` table: the last `s` with
+    /// `cum[s] <= slot`, which passes over zero-frequency symbols sharing
+    /// their `cum` entry with the next symbol.
+    fn symbol_for_slot(&self, slot: u32) -> usize {
+        self.cum.partition_point(|&c| c <= slot) - 1
+    }
+}
+
+/// Encodes `symbols` against `model`, returning the byte stream (in the order
+/// a decoder should read it, forward) and the final encoder state, which the
+/// caller must store (e.g. per sampling boundary) and pass back into
+/// [`decode_block`].
+pub fn encode_block(symbols: &[u64], model: &AnsModel) -> (Vec<u8>, u32) {
+    let mut x: u32 = ANS_L;
+    let mut reversed_bytes = Vec::new();
+
+    for &sym in symbols.iter().rev() {
+        let s = sym as usize;
+        let freq = model.freq[s];
+        debug_assert!(freq > 0, "symbol {s} has zero frequency in the trained model");
+        let cum = model.cum[s];
+
+        let x_max = ((ANS_L >> ANS_PRECISION) << 8) * freq;
+        while x >= x_max {
+            reversed_bytes.push((x & 0xff) as u8);
+            x >>= 8;
+        }
+        x = ((x / freq) << ANS_PRECISION) + (x % freq) + cum;
+    }
+
+    reversed_bytes.reverse();
+    (reversed_bytes, x)
+}
+
+/// Decodes `n` symbols from `bytes`, starting from `state` (the final state
+/// returned by [`encode_block`]).
+pub fn decode_block(bytes: &[u8], mut state: u32, n: usize, model: &AnsModel) -> Vec<u64> {
+    let mask = (1u32 << ANS_PRECISION) - 1;
+    let mut pos = 0usize;
+    let mut out = Vec::with_capacity(n);
+
+    for _ in 0..n {
+        let slot = state & mask;
+        let sym = model.symbol_for_slot(slot);
+        let freq = model.freq[sym];
+        let cum = model.cum[sym];
+
+        state = freq * (state >> ANS_PRECISION) + slot - cum;
+        while state < ANS_L {
+            state = (state << 8) | bytes[pos] as u32;
+            pos += 1;
+        }
+        out.push(sym as u64);
+    }
+
+    out
+}
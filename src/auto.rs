@@ -0,0 +1,254 @@
+//! # Automatic codec selection
+//!
+//! [`IntVec`](crate::intvec::IntVec) is generic over its codec at the type
+//! level, which means picking `GammaCodec` vs. `DeltaCodec` vs. a
+//! `RiceCodec`/`ExpGolombCodec` parameter is entirely on the caller — and a
+//! poor choice can bloat the encoded size substantially. [`AutoIntVec`] does
+//! a statistics pass over the input first: it draws a bounded sample (up to
+//! [`SAMPLE_CAP`] elements, strided evenly across the input so it stays
+//! representative without scanning everything), estimates the encoded bit
+//! length of that sample under each candidate codec — scanning a small range
+//! of `k` around `floor(log2(mean+1))` for `RiceCodec`/`ExpGolombCodec` and
+//! deriving `u = max + 1` for `MinimalBinaryCodec` — and keeps whichever
+//! candidate minimizes the total.
+//!
+//! Because the winning codec is only known at runtime, `AutoIntVec` is a
+//! small enum over the concrete [`LEIntVec`] instantiations rather than a
+//! single generic type, and forwards `get`/`iter`/`into_vec` to whichever
+//! variant was chosen.
+
+use crate::codecs::{DeltaCodec, ExpGolombCodec, GammaCodec, MinimalBinaryCodec, RiceCodec};
+use crate::intvec::{LEIntVec, LEIntVecIter};
+use std::error::Error;
+
+/// Upper bound on how many elements [`AutoIntVec::from_auto`] samples when
+/// estimating each candidate codec's encoded size.
+pub const SAMPLE_CAP: usize = 65536;
+
+/// How far above/below the mean-derived estimate to scan when selecting the
+/// `RiceCodec`/`ExpGolombCodec` parameter.
+const PARAM_SCAN_RADIUS: usize = 2;
+
+/// A compressed vector whose codec was chosen automatically by
+/// [`AutoIntVec::from_auto`].
+pub enum AutoIntVec {
+    Gamma(LEIntVec<GammaCodec>),
+    Delta(LEIntVec<DeltaCodec>),
+    ExpGolomb(LEIntVec<ExpGolombCodec>),
+    Rice(LEIntVec<RiceCodec>),
+    MinimalBinary(LEIntVec<MinimalBinaryCodec>),
+}
+
+/// Which codec [`AutoIntVec::from_auto`] picked for a given input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChosenCodec {
+    Gamma,
+    Delta,
+    ExpGolomb(usize),
+    Rice(usize),
+    MinimalBinary(u64),
+}
+
+impl AutoIntVec {
+    /// Builds an [`AutoIntVec`], sampling `input` and choosing whichever of
+    /// `GammaCodec`, `DeltaCodec`, `ExpGolombCodec`, `RiceCodec`, or
+    /// `MinimalBinaryCodec` (with parameters derived from the sample) yields
+    /// the smallest estimated encoded bitstream, then building the full
+    /// vector with that choice.
+    pub fn from_auto(input: &[u64], k: usize) -> Result<Self, Box<dyn Error>> {
+        let sample = take_sample(input);
+        let mean_log2 = estimate_mean_log2(&sample);
+        let max_bound = input.iter().copied().max().map_or(1, |m| m + 1);
+
+        let gamma_bits = measure_bits::<GammaCodec>(&sample, ())?;
+        let delta_bits = measure_bits::<DeltaCodec>(&sample, ())?;
+        let (exp_golomb_param, exp_golomb_bits) =
+            scan_param::<ExpGolombCodec>(&sample, mean_log2)?;
+        let (rice_param, rice_bits) = scan_param::<RiceCodec>(&sample, mean_log2)?;
+        let minimal_binary_bits = measure_bits::<MinimalBinaryCodec>(&sample, max_bound)?;
+
+        let smallest = gamma_bits
+            .min(delta_bits)
+            .min(exp_golomb_bits)
+            .min(rice_bits)
+            .min(minimal_binary_bits);
+
+        if smallest == gamma_bits {
+            Ok(AutoIntVec::Gamma(LEIntVec::<GammaCodec>::from(input, k)?))
+        } else if smallest == delta_bits {
+            Ok(AutoIntVec::Delta(LEIntVec::<DeltaCodec>::from(input, k)?))
+        } else if smallest == exp_golomb_bits {
+            Ok(AutoIntVec::ExpGolomb(LEIntVec::<ExpGolombCodec>::from_with_param(
+                input,
+                k,
+                exp_golomb_param,
+            )?))
+        } else if smallest == rice_bits {
+            Ok(AutoIntVec::Rice(LEIntVec::<RiceCodec>::from_with_param(
+                input, k, rice_param,
+            )?))
+        } else {
+            Ok(AutoIntVec::MinimalBinary(LEIntVec::<MinimalBinaryCodec>::from_with_param(
+                input, k, max_bound,
+            )?))
+        }
+    }
+
+    /// Reports which codec was chosen for this vector.
+    pub fn chosen_codec(&self) -> ChosenCodec {
+        match self {
+            AutoIntVec::Gamma(_) => ChosenCodec::Gamma,
+            AutoIntVec::Delta(_) => ChosenCodec::Delta,
+            AutoIntVec::ExpGolomb(v) => ChosenCodec::ExpGolomb(v.codec_param),
+            AutoIntVec::Rice(v) => ChosenCodec::Rice(v.codec_param),
+            AutoIntVec::MinimalBinary(v) => ChosenCodec::MinimalBinary(v.codec_param),
+        }
+    }
+
+    /// Retrieves the value at the given index. Panics if out of bounds.
+    pub fn get(&self, index: usize) -> u64 {
+        match self {
+            AutoIntVec::Gamma(v) => v.get(index),
+            AutoIntVec::Delta(v) => v.get(index),
+            AutoIntVec::ExpGolomb(v) => v.get(index),
+            AutoIntVec::Rice(v) => v.get(index),
+            AutoIntVec::MinimalBinary(v) => v.get(index),
+        }
+    }
+
+    /// Decompresses the entire vector back into a `Vec<u64>`.
+    pub fn into_vec(self) -> Vec<u64> {
+        match self {
+            AutoIntVec::Gamma(v) => v.into_vec(),
+            AutoIntVec::Delta(v) => v.into_vec(),
+            AutoIntVec::ExpGolomb(v) => v.into_vec(),
+            AutoIntVec::Rice(v) => v.into_vec(),
+            AutoIntVec::MinimalBinary(v) => v.into_vec(),
+        }
+    }
+
+    /// Returns the number of integers stored in the vector.
+    pub fn len(&self) -> usize {
+        match self {
+            AutoIntVec::Gamma(v) => v.len(),
+            AutoIntVec::Delta(v) => v.len(),
+            AutoIntVec::ExpGolomb(v) => v.len(),
+            AutoIntVec::Rice(v) => v.len(),
+            AutoIntVec::MinimalBinary(v) => v.len(),
+        }
+    }
+
+    /// Returns `true` if the vector holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Wraps the iterator of whichever codec variant was chosen.
+pub enum AutoIntVecIter<'a> {
+    Gamma(LEIntVecIter<'a, GammaCodec>),
+    Delta(LEIntVecIter<'a, DeltaCodec>),
+    ExpGolomb(LEIntVecIter<'a, ExpGolombCodec>),
+    Rice(LEIntVecIter<'a, RiceCodec>),
+    MinimalBinary(LEIntVecIter<'a, MinimalBinaryCodec>),
+}
+
+impl AutoIntVec {
+    /// Returns an iterator over the decompressed values.
+    pub fn iter(&self) -> AutoIntVecIter<'_> {
+        match self {
+            AutoIntVec::Gamma(v) => AutoIntVecIter::Gamma(v.iter()),
+            AutoIntVec::Delta(v) => AutoIntVecIter::Delta(v.iter()),
+            AutoIntVec::ExpGolomb(v) => AutoIntVecIter::ExpGolomb(v.iter()),
+            AutoIntVec::Rice(v) => AutoIntVecIter::Rice(v.iter()),
+            AutoIntVec::MinimalBinary(v) => AutoIntVecIter::MinimalBinary(v.iter()),
+        }
+    }
+}
+
+impl Iterator for AutoIntVecIter<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        match self {
+            AutoIntVecIter::Gamma(it) => it.next(),
+            AutoIntVecIter::Delta(it) => it.next(),
+            AutoIntVecIter::ExpGolomb(it) => it.next(),
+            AutoIntVecIter::Rice(it) => it.next(),
+            AutoIntVecIter::MinimalBinary(it) => it.next(),
+        }
+    }
+}
+
+/// Draws a bounded, evenly-strided sample of up to [`SAMPLE_CAP`] elements
+/// from `input`, so estimation stays cheap for very large inputs without
+/// biasing towards any single region of the data.
+fn take_sample(input: &[u64]) -> Vec<u64> {
+    if input.len() <= SAMPLE_CAP {
+        return input.to_vec();
+    }
+    let stride = input.len() / SAMPLE_CAP;
+    input.iter().copied().step_by(stride).take(SAMPLE_CAP).collect()
+}
+
+/// Estimates `floor(log2(mean(sample) + 1))`, the geometric-distribution
+/// parameter estimate shared by `RiceCodec` and `ExpGolombCodec`.
+fn estimate_mean_log2(sample: &[u64]) -> usize {
+    if sample.is_empty() {
+        return 0;
+    }
+    let mean = sample.iter().map(|&v| v as u128).sum::<u128>() / sample.len() as u128;
+    (64 - (mean as u64 + 1).leading_zeros() as usize).saturating_sub(1)
+}
+
+/// Scans `k` in `[center.saturating_sub(PARAM_SCAN_RADIUS), center + PARAM_SCAN_RADIUS]`
+/// and returns the parameter/bit-length pair that minimizes `C`'s encoded
+/// size over `sample`.
+fn scan_param<C>(sample: &[u64], center: usize) -> Result<(usize, usize), Box<dyn Error>>
+where
+    C: crate::codecs::Codec<
+        dsi_bitstream::traits::LE,
+        dsi_bitstream::impls::BufBitWriter<
+            dsi_bitstream::traits::LE,
+            dsi_bitstream::impls::MemWordWriterVec<u64, Vec<u64>>,
+        >,
+        Params = usize,
+    >,
+{
+    let lo = center.saturating_sub(PARAM_SCAN_RADIUS);
+    let hi = center + PARAM_SCAN_RADIUS;
+
+    let mut best = (lo, usize::MAX);
+    for k in lo..=hi {
+        let bits = measure_bits::<C>(sample, k)?;
+        if bits < best.1 {
+            best = (k, bits);
+        }
+    }
+    Ok(best)
+}
+
+/// Encodes `input` with codec `C` into a scratch buffer and returns the total
+/// number of bits written, without keeping the encoded data around.
+fn measure_bits<C>(input: &[u64], param: C::Params) -> Result<usize, Box<dyn Error>>
+where
+    C: crate::codecs::Codec<
+        dsi_bitstream::traits::LE,
+        dsi_bitstream::impls::BufBitWriter<
+            dsi_bitstream::traits::LE,
+            dsi_bitstream::impls::MemWordWriterVec<u64, Vec<u64>>,
+        >,
+    >,
+    C::Params: Copy,
+{
+    use dsi_bitstream::impls::{BufBitWriter, MemWordWriterVec};
+    use dsi_bitstream::traits::LE;
+
+    let word_writer = MemWordWriterVec::new(Vec::new());
+    let mut writer = BufBitWriter::<LE, MemWordWriterVec<u64, Vec<u64>>>::new(word_writer);
+    let mut total_bits = 0;
+    for &x in input {
+        total_bits += C::encode(&mut writer, x, param)?;
+    }
+    Ok(total_bits)
+}
@@ -0,0 +1,109 @@
+//! # Analytic codec selection
+//!
+//! [`auto`](crate::auto)'s [`AutoIntVec`](crate::auto::AutoIntVec) estimates
+//! each candidate codec's cost by actually encoding a sample into a scratch
+//! buffer ([`measure_bits`](crate::auto) in that module). Now that
+//! [`Codec::bit_len`] exists, the same comparison can be done analytically —
+//! summing `bit_len` over a slice of samples costs nothing but arithmetic,
+//! no writer or scratch buffer required. [`select_best_codec`] does exactly
+//! that: it sums the bit length every candidate in [`CodecChoice`] would
+//! need for `samples`, sweeping the runtime parameter for `RiceCodec` and
+//! `ExpGolombCodec` over `0..=` the sample's maximum bit width and
+//! `ZetaCodec`'s order over `1..=` that width (at least `1`), and returns
+//! the minimizer.
+//!
+//! The result is a plain descriptor, not a vector — callers use it to decide
+//! which codec to build the real [`IntVec`](crate::intvec::IntVec) with (for
+//! example via [`LEIntVec::from`](crate::intvec::LEIntVec::from) /
+//! [`from_with_param`](crate::intvec::LEIntVec::from_with_param)), so the
+//! potentially-large sample used for selection never has to be the same
+//! buffer that gets encoded for real.
+
+use crate::codecs::{Codec, DeltaCodec, ExpGolombCodec, GammaCodec, RiceCodec, ZetaCodec};
+use dsi_bitstream::impls::{BufBitWriter, MemWordWriterVec};
+use dsi_bitstream::traits::LE;
+
+/// The writer type used purely to select `Codec<LE, _>` impls; `bit_len`
+/// never actually writes through it.
+type Writer = BufBitWriter<LE, MemWordWriterVec<u64, Vec<u64>>>;
+
+/// Which codec (and, for parametric codecs, which parameter) [`select_best_codec`]
+/// found cheapest for a given sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodecChoice {
+    Gamma,
+    Delta,
+    ExpGolomb(usize),
+    Rice(usize),
+    Zeta(u64),
+}
+
+/// Sum of `C::bit_len(value, param)` over every value in `samples`.
+fn total_bits<C>(samples: &[u64], param: C::Params) -> usize
+where
+    C: Codec<LE, Writer>,
+    C::Params: Copy,
+{
+    samples.iter().map(|&v| C::bit_len(v, param)).sum()
+}
+
+/// Number of bits needed to represent the largest value in `samples`, used
+/// to bound the parameter sweep for the parametric codecs below (`0` if
+/// `samples` is empty).
+fn max_significant_bits(samples: &[u64]) -> usize {
+    samples.iter().copied().max().map_or(0, |m| 64 - m.leading_zeros() as usize)
+}
+
+/// Sweeps `param in 0..=max_param` and returns the value/total-bits pair
+/// that minimizes `total_bits::<C>(samples, param)`.
+fn sweep_param<C>(samples: &[u64], max_param: usize) -> (usize, usize)
+where
+    C: Codec<LE, Writer, Params = usize>,
+{
+    (0..=max_param)
+        .map(|param| (param, total_bits::<C>(samples, param)))
+        .min_by_key(|&(_, bits)| bits)
+        .unwrap_or((0, 0))
+}
+
+/// Sweeps `ZetaCodec`'s `k in 1..=max_param.max(1)` (`k = 0` is not a valid
+/// zeta order) and returns the value/total-bits pair that minimizes the
+/// total.
+fn sweep_zeta(samples: &[u64], max_param: usize) -> (u64, usize) {
+    (1..=max_param.max(1) as u64)
+        .map(|k| (k, total_bits::<ZetaCodec>(samples, k)))
+        .min_by_key(|&(_, bits)| bits)
+        .unwrap_or((1, 0))
+}
+
+/// Computes the total encoded bit length `samples` would need under each of
+/// `GammaCodec`, `DeltaCodec`, `ExpGolombCodec`, `RiceCodec` and `ZetaCodec`
+/// (sweeping the runtime parameter for the latter three) and returns
+/// whichever [`CodecChoice`] minimizes it.
+///
+/// Ties favor the earlier candidate in the list above, matching
+/// [`AutoIntVec::from_auto`](crate::auto::AutoIntVec::from_auto)'s
+/// `min`-chain tie-breaking.
+pub fn select_best_codec(samples: &[u64]) -> CodecChoice {
+    let max_param = max_significant_bits(samples);
+
+    let gamma_bits = total_bits::<GammaCodec>(samples, ());
+    let delta_bits = total_bits::<DeltaCodec>(samples, ());
+    let (exp_golomb_param, exp_golomb_bits) = sweep_param::<ExpGolombCodec>(samples, max_param);
+    let (rice_param, rice_bits) = sweep_param::<RiceCodec>(samples, max_param);
+    let (zeta_param, zeta_bits) = sweep_zeta(samples, max_param);
+
+    let smallest = gamma_bits.min(delta_bits).min(exp_golomb_bits).min(rice_bits).min(zeta_bits);
+
+    if smallest == gamma_bits {
+        CodecChoice::Gamma
+    } else if smallest == delta_bits {
+        CodecChoice::Delta
+    } else if smallest == exp_golomb_bits {
+        CodecChoice::ExpGolomb(exp_golomb_param)
+    } else if smallest == rice_bits {
+        CodecChoice::Rice(rice_param)
+    } else {
+        CodecChoice::Zeta(zeta_param)
+    }
+}
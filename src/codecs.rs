@@ -42,6 +42,177 @@ pub trait Codec<E: Endianness, W: BitWrite<E>> {
             + DeltaReadParam<E>
             + GammaReadParam<E>
             + MinimalBinaryRead<E>;
+
+    /// Returns the number of bits `encode` would write for `value`, without
+    /// writing anything. Lets callers size buffers exactly and compare
+    /// codecs' cost on a dataset without paying for an actual encode.
+    fn bit_len(value: u64, params: Self::Params) -> usize;
+
+    /// Advances `reader` past exactly one code without reconstructing its
+    /// value, returning the number of bits consumed. Where the code's
+    /// length can be determined without decoding the full value (e.g. by
+    /// peeking a unary run), this is cheaper than `decode` followed by
+    /// discarding the result; it gives O(1)-per-element forward traversal
+    /// for building sampled index checkpoints.
+    fn skip<R2>(reader: &mut R2, params: Self::Params) -> Result<usize, Box<dyn Error>>
+    where
+        R2: for<'a> GammaRead<E>
+            + DeltaRead<E>
+            + ExpGolombRead<E>
+            + ZetaRead<E>
+            + RiceRead<E>
+            + ZetaReadParam<E>
+            + DeltaReadParam<E>
+            + GammaReadParam<E>
+            + MinimalBinaryRead<E>;
+}
+
+/// `⌊log2(v)⌋`, one less than the number of bits needed to represent `v`
+/// in binary.
+///
+/// `v` must be at least `1`; every call site below derives `v` from
+/// `value + 1`, which is always positive.
+#[inline(always)]
+fn floor_log2(v: u128) -> usize {
+    debug_assert!(v >= 1);
+    127 - v.leading_zeros() as usize
+}
+
+/// Bit length of `value` under the gamma code: `2 * ⌊log2(value + 1)⌋ + 1`.
+/// Shared by every codec ([`GammaCodec`], [`DeltaCodec`], [`ExpGolombCodec`],
+/// the `Param*` variants) that is itself built on top of gamma coding.
+#[inline(always)]
+fn gamma_bit_len(value: u64) -> usize {
+    2 * floor_log2(value as u128 + 1) + 1
+}
+
+/// Bit length of `value` under the delta code: a gamma-coded bit length `L`
+/// followed by the `L - 1` mantissa bits below the implicit leading one.
+#[inline(always)]
+fn delta_bit_len(value: u64) -> usize {
+    let n = value as u128 + 1;
+    let l = floor_log2(n) + 1;
+    gamma_bit_len(l as u64 - 1) + (l - 1)
+}
+
+/// Bit length of `value` under minimal binary coding over `0..upper_bound`
+/// (the bound itself excluded): `⌊log2(n)⌋` bits for the first `threshold`
+/// values and `⌊log2(n)⌋ + 1` for the rest, where `n = upper_bound`.
+#[inline(always)]
+fn minimal_binary_bit_len(value: u64, upper_bound: u64) -> usize {
+    let n = upper_bound as u128;
+    let s = floor_log2(n);
+    let threshold = (1u128 << (s + 1)) - n;
+    if (value as u128) < threshold {
+        s
+    } else {
+        s + 1
+    }
+}
+
+/// Bit length of `value` under the zeta code of order `k`: a unary-coded
+/// major group index followed by a minimal-binary-coded offset within that
+/// group's range, following Vigna's zeta code construction.
+#[inline(always)]
+fn zeta_bit_len(value: u64, k: u64) -> usize {
+    let v = value as u128 + 1;
+    let h = floor_log2(v) as u64 / k;
+    let lower = 1u128 << (h * k);
+    let upper = 1u128 << ((h + 1) * k);
+    let domain = upper - lower;
+    let s = floor_log2(domain);
+    let threshold = (1u128 << (s + 1)) - domain;
+    let offset = v - lower;
+    let min_bin_len = if offset < threshold { s } else { s + 1 };
+    (h + 1) as usize + min_bin_len
+}
+
+/// Skips one minimal-binary code over `0..upper_bound` (the bound itself
+/// excluded): reads the `s`-bit prefix (needed to compare against
+/// `threshold`, the same way [`minimal_binary_bit_len`] does), then skips one
+/// more bit only if the prefix is at least `threshold`. Shared by
+/// [`MinimalBinaryCodec`] and, via [`zeta_skip`], every zeta variant.
+#[inline(always)]
+fn minimal_binary_skip<E: Endianness, R: BitRead<E>>(
+    reader: &mut R,
+    upper_bound: u64,
+) -> Result<usize, Box<dyn Error>> {
+    let n = upper_bound as u128;
+    let s = floor_log2(n);
+    let threshold = (1u128 << (s + 1)) - n;
+    let prefix = reader.read_bits(s)? as u128;
+    if prefix < threshold {
+        Ok(s)
+    } else {
+        reader.skip_bits(1)?;
+        Ok(s + 1)
+    }
+}
+
+/// Skips one gamma code: reads the leading unary run to learn the mantissa
+/// length `ℓ`, then skips the `ℓ` mantissa bits without reconstructing the
+/// value, for `2ℓ + 1` bits total. Shared by [`GammaCodec`],
+/// [`ParamGammaCodec`] and [`ExpGolombCodec`] with `k = 0`.
+#[inline(always)]
+fn gamma_skip<E: Endianness, R: BitRead<E>>(reader: &mut R) -> Result<usize, Box<dyn Error>> {
+    let l = reader.read_unary()? as usize;
+    reader.skip_bits(l)?;
+    Ok(2 * l + 1)
+}
+
+/// Skips one delta code: decodes the gamma-coded mantissa length `L - 1`
+/// (its value, not just its length, is needed to know how many mantissa
+/// bits follow), then skips those `L - 1` bits. Shared by [`DeltaCodec`]
+/// and [`ParamDeltaCodec`].
+#[inline(always)]
+fn delta_skip<E: Endianness, R: GammaRead<E> + BitRead<E>>(
+    reader: &mut R,
+) -> Result<usize, Box<dyn Error>> {
+    let l_minus_1 = reader.read_gamma()?;
+    let mantissa_bits = l_minus_1 as usize;
+    reader.skip_bits(mantissa_bits)?;
+    Ok(gamma_bit_len(l_minus_1) + mantissa_bits)
+}
+
+/// Skips one Exp-Golomb code of order `k`: a gamma code over `value >> k`
+/// followed by `k` fixed remainder bits (plain gamma when `k == 0`).
+#[inline(always)]
+fn exp_golomb_skip<E: Endianness, R: BitRead<E>>(
+    reader: &mut R,
+    k: usize,
+) -> Result<usize, Box<dyn Error>> {
+    if k == 0 {
+        return gamma_skip(reader);
+    }
+    let l = reader.read_unary()? as usize;
+    reader.skip_bits(l + k)?;
+    Ok(2 * l + 1 + k)
+}
+
+/// Skips one Rice code with parameter `log2_b`: the unary-coded quotient
+/// (via [`BitRead::read_unary`]) followed by the `log2_b` fixed remainder
+/// bits, which don't need to be inspected to know their count.
+#[inline(always)]
+fn rice_skip<E: Endianness, R: BitRead<E>>(
+    reader: &mut R,
+    log2_b: usize,
+) -> Result<usize, Box<dyn Error>> {
+    let quotient = reader.read_unary()? as usize;
+    reader.skip_bits(log2_b)?;
+    Ok(quotient + 1 + log2_b)
+}
+
+/// Skips one zeta code of order `k`: reads the unary-coded major group
+/// index `h`, then skips the minimal-binary-coded offset within that
+/// group's range via [`minimal_binary_skip`], mirroring [`zeta_bit_len`].
+#[inline(always)]
+fn zeta_skip<E: Endianness, R: BitRead<E>>(reader: &mut R, k: u64) -> Result<usize, Box<dyn Error>> {
+    let h = reader.read_unary()?;
+    let lower = 1u128 << (h * k);
+    let upper = 1u128 << ((h + 1) * k);
+    let domain = (upper - lower) as u64;
+    let min_bin_len = minimal_binary_skip(reader, domain)?;
+    Ok((h + 1) as usize + min_bin_len)
 }
 
 /// MinimalBinaryCodec: uses an upper bound as a runtime parameter.
@@ -64,6 +235,19 @@ impl<E: Endianness, W: MinimalBinaryWrite<E>> Codec<E, W> for MinimalBinaryCodec
     ) -> Result<u64, Box<dyn Error>> {
         Ok(reader.read_minimal_binary(upper_bound)?)
     }
+
+    #[inline(always)]
+    fn bit_len(value: u64, upper_bound: u64) -> usize {
+        minimal_binary_bit_len(value, upper_bound)
+    }
+
+    #[inline(always)]
+    fn skip<R: MinimalBinaryRead<E>>(
+        reader: &mut R,
+        upper_bound: u64,
+    ) -> Result<usize, Box<dyn Error>> {
+        minimal_binary_skip(reader, upper_bound)
+    }
 }
 
 impl MinimalBinaryCodec {
@@ -104,6 +288,16 @@ impl<E: Endianness, W: GammaWrite<E>> Codec<E, W> for GammaCodec {
     fn decode<R: GammaRead<E>>(reader: &mut R, _params: ()) -> Result<u64, Box<dyn Error>> {
         Ok(reader.read_gamma()?)
     }
+
+    #[inline(always)]
+    fn bit_len(value: u64, _params: ()) -> usize {
+        gamma_bit_len(value)
+    }
+
+    #[inline(always)]
+    fn skip<R: GammaRead<E>>(reader: &mut R, _params: ()) -> Result<usize, Box<dyn Error>> {
+        gamma_skip(reader)
+    }
 }
 
 impl GammaCodec {
@@ -142,6 +336,19 @@ impl<E: Endianness, W: DeltaWrite<E>> Codec<E, W> for DeltaCodec {
     fn decode<R: DeltaRead<E>>(reader: &mut R, _params: ()) -> Result<u64, Box<dyn Error>> {
         Ok(reader.read_delta()?)
     }
+
+    #[inline(always)]
+    fn bit_len(value: u64, _params: ()) -> usize {
+        delta_bit_len(value)
+    }
+
+    #[inline(always)]
+    fn skip<R: DeltaRead<E> + GammaRead<E>>(
+        reader: &mut R,
+        _params: (),
+    ) -> Result<usize, Box<dyn Error>> {
+        delta_skip(reader)
+    }
 }
 
 impl DeltaCodec {
@@ -182,6 +389,21 @@ impl<E: Endianness, W: ExpGolombWrite<E>> Codec<E, W> for ExpGolombCodec {
     fn decode<R: ExpGolombRead<E>>(reader: &mut R, k: usize) -> Result<u64, Box<dyn Error>> {
         Ok(reader.read_exp_golomb(k)?)
     }
+
+    /// A gamma code over `value >> k` followed by `k` fixed remainder bits;
+    /// `k = 0` is plain gamma coding.
+    #[inline(always)]
+    fn bit_len(value: u64, k: usize) -> usize {
+        if k == 0 {
+            return gamma_bit_len(value);
+        }
+        gamma_bit_len(value >> k) + k
+    }
+
+    #[inline(always)]
+    fn skip<R: ExpGolombRead<E>>(reader: &mut R, k: usize) -> Result<usize, Box<dyn Error>> {
+        exp_golomb_skip(reader, k)
+    }
 }
 
 impl ExpGolombCodec {
@@ -222,6 +444,16 @@ impl<E: Endianness, W: ZetaWrite<E>> Codec<E, W> for ZetaCodec {
     fn decode<R: ZetaRead<E>>(reader: &mut R, k: u64) -> Result<u64, Box<dyn Error>> {
         Ok(reader.read_zeta(k)?)
     }
+
+    #[inline(always)]
+    fn bit_len(value: u64, k: u64) -> usize {
+        zeta_bit_len(value, k)
+    }
+
+    #[inline(always)]
+    fn skip<R: ZetaRead<E>>(reader: &mut R, k: u64) -> Result<usize, Box<dyn Error>> {
+        zeta_skip(reader, k)
+    }
 }
 
 impl ZetaCodec {
@@ -262,6 +494,18 @@ impl<E: Endianness, W: RiceWrite<E>> Codec<E, W> for RiceCodec {
     fn decode<R: RiceRead<E>>(reader: &mut R, log2_b: usize) -> Result<u64, Box<dyn Error>> {
         Ok(reader.read_rice(log2_b)?)
     }
+
+    /// The unary-coded quotient (`value >> log2_b` zeros/ones plus a
+    /// terminator) followed by the `log2_b` fixed remainder bits.
+    #[inline(always)]
+    fn bit_len(value: u64, log2_b: usize) -> usize {
+        ((value >> log2_b) + 1) as usize + log2_b
+    }
+
+    #[inline(always)]
+    fn skip<R: RiceRead<E>>(reader: &mut R, log2_b: usize) -> Result<usize, Box<dyn Error>> {
+        rice_skip(reader, log2_b)
+    }
 }
 
 impl RiceCodec {
@@ -304,6 +548,18 @@ impl<E: Endianness, W: ZetaWriteParam<E>, const USE_TABLE: bool> Codec<E, W>
     fn decode<R: ZetaReadParam<E>>(reader: &mut R, _params: ()) -> Result<u64, Box<dyn Error>> {
         Ok(reader.read_zeta3_param::<USE_TABLE>()?)
     }
+
+    /// `USE_TABLE` only changes how the code is looked up, not its length;
+    /// `zeta3_param` is the zeta code of order `3`.
+    #[inline(always)]
+    fn bit_len(value: u64, _params: ()) -> usize {
+        zeta_bit_len(value, 3)
+    }
+
+    #[inline(always)]
+    fn skip<R: ZetaReadParam<E>>(reader: &mut R, _params: ()) -> Result<usize, Box<dyn Error>> {
+        zeta_skip(reader, 3)
+    }
 }
 
 impl<const USE_TABLE: bool> ParamZetaCodec<USE_TABLE> {
@@ -348,6 +604,20 @@ impl<
     fn decode<R: DeltaReadParam<E>>(reader: &mut R, _params: ()) -> Result<u64, Box<dyn Error>> {
         Ok(reader.read_delta_param::<USE_DELTA_TABLE, USE_GAMMA_TABLE>()?)
     }
+
+    /// The table flags only change how the code is looked up, not its length.
+    #[inline(always)]
+    fn bit_len(value: u64, _params: ()) -> usize {
+        delta_bit_len(value)
+    }
+
+    #[inline(always)]
+    fn skip<R: DeltaReadParam<E> + GammaRead<E>>(
+        reader: &mut R,
+        _params: (),
+    ) -> Result<usize, Box<dyn Error>> {
+        delta_skip(reader)
+    }
 }
 
 impl<const USE_DELTA_TABLE: bool, const USE_GAMMA_TABLE: bool>
@@ -388,6 +658,17 @@ impl<E: Endianness, W: GammaWriteParam<E>, const USE_TABLE: bool> Codec<E, W>
     fn decode<R: GammaReadParam<E>>(reader: &mut R, _params: ()) -> Result<u64, Box<dyn Error>> {
         Ok(reader.read_gamma_param::<USE_TABLE>()?)
     }
+
+    /// `USE_TABLE` only changes how the code is looked up, not its length.
+    #[inline(always)]
+    fn bit_len(value: u64, _params: ()) -> usize {
+        gamma_bit_len(value)
+    }
+
+    #[inline(always)]
+    fn skip<R: GammaReadParam<E>>(reader: &mut R, _params: ()) -> Result<usize, Box<dyn Error>> {
+        gamma_skip(reader)
+    }
 }
 
 impl<const USE_TABLE: bool> ParamGammaCodec<USE_TABLE> {
@@ -408,3 +689,789 @@ impl<const USE_TABLE: bool> ParamGammaCodec<USE_TABLE> {
         Ok(reader.read_gamma_param::<USE_TABLE>()?)
     }
 }
+
+/// CompactCodec: a self-describing, byte-aligned variable-length integer encoding,
+/// in the style of SCALE's compact integers.
+///
+/// No extra runtime parameter is required. Unlike the bit-level universal codes above,
+/// every encoded value is byte-aligned, which makes this codec attractive when
+/// interoperating with systems that expect byte-granular integers or when decode speed
+/// matters more than a few extra bits of overhead.
+///
+/// The low two bits of the first byte select the mode:
+///
+/// - `00`: single byte, value `< 2^6`, stored as `value << 2`.
+/// - `01`: two bytes, value `< 2^14`, stored little-endian as `(value << 2) | 1`.
+/// - `10`: four bytes, value `< 2^30`, stored little-endian as `(value << 2) | 2`.
+/// - `11`: big-integer mode. The first byte is `((byte_len - 4) << 2) | 3`, followed by
+///   `byte_len` little-endian bytes of the value.
+pub struct CompactCodec;
+
+impl<E: Endianness, W: BitWrite<E>> Codec<E, W> for CompactCodec {
+    type Params = ();
+
+    #[inline(always)]
+    fn encode(writer: &mut W, value: u64, _params: ()) -> Result<usize, Box<dyn Error>> {
+        CompactCodec::encode(writer, value)
+    }
+
+    #[inline(always)]
+    fn decode<R2>(reader: &mut R2, _params: ()) -> Result<u64, Box<dyn Error>>
+    where
+        R2: for<'a> GammaRead<E>
+            + DeltaRead<E>
+            + ExpGolombRead<E>
+            + ZetaRead<E>
+            + RiceRead<E>
+            + ZetaReadParam<E>
+            + DeltaReadParam<E>
+            + GammaReadParam<E>
+            + MinimalBinaryRead<E>,
+    {
+        CompactCodec::decode(reader)
+    }
+
+    #[inline(always)]
+    fn bit_len(value: u64, _params: ()) -> usize {
+        CompactCodec::bit_len(value)
+    }
+
+    #[inline(always)]
+    fn skip<R2>(reader: &mut R2, _params: ()) -> Result<usize, Box<dyn Error>>
+    where
+        R2: for<'a> GammaRead<E>
+            + DeltaRead<E>
+            + ExpGolombRead<E>
+            + ZetaRead<E>
+            + RiceRead<E>
+            + ZetaReadParam<E>
+            + DeltaReadParam<E>
+            + GammaReadParam<E>
+            + MinimalBinaryRead<E>,
+    {
+        CompactCodec::skip(reader)
+    }
+}
+
+impl CompactCodec {
+    /// Writes a single little-endian byte via the bit-level writer.
+    #[inline(always)]
+    fn write_byte<E: Endianness, W: BitWrite<E>>(
+        writer: &mut W,
+        byte: u8,
+    ) -> Result<usize, Box<dyn Error>> {
+        Ok(writer.write_bits(byte as u64, 8)?)
+    }
+
+    /// Reads a single byte via the bit-level reader.
+    #[inline(always)]
+    fn read_byte<E: Endianness, R: BitRead<E>>(reader: &mut R) -> Result<u8, Box<dyn Error>> {
+        Ok(reader.read_bits(8)? as u8)
+    }
+
+    /// Encodes a value using the compact varint encoding described above.
+    pub fn encode<E: Endianness, W: BitWrite<E>>(
+        writer: &mut W,
+        value: u64,
+    ) -> Result<usize, Box<dyn Error>> {
+        let mut bits = 0;
+        if value < (1 << 6) {
+            bits += Self::write_byte(writer, (value << 2) as u8)?;
+        } else if value < (1 << 14) {
+            let packed = ((value << 2) | 1) as u16;
+            for byte in packed.to_le_bytes() {
+                bits += Self::write_byte(writer, byte)?;
+            }
+        } else if value < (1 << 30) {
+            let packed = ((value << 2) | 2) as u32;
+            for byte in packed.to_le_bytes() {
+                bits += Self::write_byte(writer, byte)?;
+            }
+        } else {
+            let value_bytes = value.to_le_bytes();
+            let significant_bytes = (64 - value.leading_zeros() as usize).div_ceil(8);
+            let byte_len = significant_bytes.clamp(4, 8);
+            bits += Self::write_byte(writer, (((byte_len - 4) << 2) | 3) as u8)?;
+            for &byte in &value_bytes[..byte_len] {
+                bits += Self::write_byte(writer, byte)?;
+            }
+        }
+        Ok(bits)
+    }
+
+    /// Decodes a value using the compact varint encoding described above.
+    pub fn decode<E: Endianness, R: BitRead<E>>(reader: &mut R) -> Result<u64, Box<dyn Error>> {
+        let first = Self::read_byte(reader)?;
+        match first & 0b11 {
+            0b00 => Ok((first >> 2) as u64),
+            0b01 => {
+                let second = Self::read_byte(reader)?;
+                let packed = u16::from_le_bytes([first, second]);
+                Ok((packed >> 2) as u64)
+            }
+            0b10 => {
+                let mut bytes = [first, 0, 0, 0];
+                for byte in &mut bytes[1..] {
+                    *byte = Self::read_byte(reader)?;
+                }
+                let packed = u32::from_le_bytes(bytes);
+                Ok((packed >> 2) as u64)
+            }
+            _ => {
+                let byte_len = ((first >> 2) as usize) + 4;
+                let mut value_bytes = [0u8; 8];
+                for byte in value_bytes.iter_mut().take(byte_len) {
+                    *byte = Self::read_byte(reader)?;
+                }
+                Ok(u64::from_le_bytes(value_bytes))
+            }
+        }
+    }
+
+    /// Bit length of `value`'s compact encoding: always a whole number of
+    /// bytes, per the mode selection in [`CompactCodec::encode`].
+    fn bit_len(value: u64) -> usize {
+        if value < (1 << 6) {
+            8
+        } else if value < (1 << 14) {
+            16
+        } else if value < (1 << 30) {
+            32
+        } else {
+            let significant_bytes = (64 - value.leading_zeros() as usize).div_ceil(8);
+            let byte_len = significant_bytes.clamp(4, 8);
+            (byte_len + 1) * 8
+        }
+    }
+
+    /// Skips one compact-encoded value: reads the mode tag from the first
+    /// byte (needed to know how many bytes follow) and skips the rest
+    /// without assembling them into a value.
+    fn skip<E: Endianness, R: BitRead<E>>(reader: &mut R) -> Result<usize, Box<dyn Error>> {
+        let first = Self::read_byte(reader)?;
+        let byte_len = match first & 0b11 {
+            0b00 => 1,
+            0b01 => 2,
+            0b10 => 4,
+            _ => ((first >> 2) as usize) + 4 + 1,
+        };
+        reader.skip_bits((byte_len - 1) * 8)?;
+        Ok(byte_len * 8)
+    }
+}
+
+/// StreamVByteCodec: a byte-aligned, tagged-length varint codec in the spirit
+/// of Google's [Stream VByte](https://arxiv.org/abs/1709.08990).
+///
+/// Each value is stored as a one-byte length tag (`byte_len - 1`, so `0..=7`
+/// for the full `u64` range) followed by that many little-endian bytes —
+/// the minimum needed to represent the value, with `0` itself stored as a
+/// single zero byte. Decoding is a tight byte scan with no bit shuffling,
+/// which makes this considerably faster to decode than the bit-level Elias
+/// codes above at a small space cost.
+///
+/// > **Note:** the original Stream VByte format packs the length tags of
+/// > *four* values into one control byte and decodes the whole group with a
+/// > single SIMD shuffle. That requires a block-oriented encode/decode hook
+/// > (the [`IntVec`](crate::intvec::IntVec) construction loop calls
+/// > [`Codec::encode`] one value at a time — see the rANS codec in
+/// > [`ans`](crate::ans) for the same limitation), so this codec instead
+/// > gives every value its own one-byte tag. It keeps the byte-aligned,
+/// > branch-light decode that matters for throughput while fitting the
+/// > crate's per-value codec abstraction; grouping four tags into one
+/// > control byte is future work once `IntVec` grows a block-level path.
+pub struct StreamVByteCodec;
+
+impl<E: Endianness, W: BitWrite<E>> Codec<E, W> for StreamVByteCodec {
+    type Params = ();
+
+    #[inline(always)]
+    fn encode(writer: &mut W, value: u64, _params: ()) -> Result<usize, Box<dyn Error>> {
+        StreamVByteCodec::encode(writer, value)
+    }
+
+    #[inline(always)]
+    fn decode<R2>(reader: &mut R2, _params: ()) -> Result<u64, Box<dyn Error>>
+    where
+        R2: for<'a> GammaRead<E>
+            + DeltaRead<E>
+            + ExpGolombRead<E>
+            + ZetaRead<E>
+            + RiceRead<E>
+            + ZetaReadParam<E>
+            + DeltaReadParam<E>
+            + GammaReadParam<E>
+            + MinimalBinaryRead<E>,
+    {
+        StreamVByteCodec::decode(reader)
+    }
+
+    #[inline(always)]
+    fn bit_len(value: u64, _params: ()) -> usize {
+        (1 + Self::byte_len(value)) * 8
+    }
+
+    #[inline(always)]
+    fn skip<R2>(reader: &mut R2, _params: ()) -> Result<usize, Box<dyn Error>>
+    where
+        R2: for<'a> GammaRead<E>
+            + DeltaRead<E>
+            + ExpGolombRead<E>
+            + ZetaRead<E>
+            + RiceRead<E>
+            + ZetaReadParam<E>
+            + DeltaReadParam<E>
+            + GammaReadParam<E>
+            + MinimalBinaryRead<E>,
+    {
+        StreamVByteCodec::skip(reader)
+    }
+}
+
+impl StreamVByteCodec {
+    /// Writes a single little-endian byte via the bit-level writer.
+    #[inline(always)]
+    fn write_byte<E: Endianness, W: BitWrite<E>>(
+        writer: &mut W,
+        byte: u8,
+    ) -> Result<usize, Box<dyn Error>> {
+        Ok(writer.write_bits(byte as u64, 8)?)
+    }
+
+    /// Reads a single byte via the bit-level reader.
+    #[inline(always)]
+    fn read_byte<E: Endianness, R: BitRead<E>>(reader: &mut R) -> Result<u8, Box<dyn Error>> {
+        Ok(reader.read_bits(8)? as u8)
+    }
+
+    /// Number of bytes needed to hold `value`, minimum `1` (so `0` itself
+    /// round-trips as a single zero byte).
+    #[inline(always)]
+    fn byte_len(value: u64) -> usize {
+        (64 - value.leading_zeros() as usize).div_ceil(8).max(1)
+    }
+
+    /// Encodes a value as a one-byte length tag followed by that many
+    /// little-endian bytes.
+    pub fn encode<E: Endianness, W: BitWrite<E>>(
+        writer: &mut W,
+        value: u64,
+    ) -> Result<usize, Box<dyn Error>> {
+        let len = Self::byte_len(value);
+        let mut bits = Self::write_byte(writer, (len - 1) as u8)?;
+        for byte in &value.to_le_bytes()[..len] {
+            bits += Self::write_byte(writer, *byte)?;
+        }
+        Ok(bits)
+    }
+
+    /// Decodes a value encoded by [`StreamVByteCodec::encode`].
+    pub fn decode<E: Endianness, R: BitRead<E>>(reader: &mut R) -> Result<u64, Box<dyn Error>> {
+        let len = Self::read_byte(reader)? as usize + 1;
+        let mut bytes = [0u8; 8];
+        for byte in bytes.iter_mut().take(len) {
+            *byte = Self::read_byte(reader)?;
+        }
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    /// Skips one value encoded by [`StreamVByteCodec::encode`]: reads the
+    /// one-byte length tag and skips the rest without assembling them.
+    fn skip<E: Endianness, R: BitRead<E>>(reader: &mut R) -> Result<usize, Box<dyn Error>> {
+        let len = Self::read_byte(reader)? as usize + 1;
+        reader.skip_bits(len * 8)?;
+        Ok((1 + len) * 8)
+    }
+}
+
+/// VarIntCodec: the classic LEB128 variable-length byte encoding.
+///
+/// The value is split into 7-bit groups, least-significant group first; every
+/// byte but the last has its high bit set to signal that more groups follow.
+/// Like [`StreamVByteCodec`], this trades a little space for a byte-aligned,
+/// branch-light decode loop with no bit shuffling, and requires no extra
+/// runtime parameter.
+pub struct VarIntCodec;
+
+impl<E: Endianness, W: BitWrite<E>> Codec<E, W> for VarIntCodec {
+    type Params = ();
+
+    #[inline(always)]
+    fn encode(writer: &mut W, value: u64, _params: ()) -> Result<usize, Box<dyn Error>> {
+        VarIntCodec::encode(writer, value)
+    }
+
+    #[inline(always)]
+    fn decode<R2>(reader: &mut R2, _params: ()) -> Result<u64, Box<dyn Error>>
+    where
+        R2: for<'a> GammaRead<E>
+            + DeltaRead<E>
+            + ExpGolombRead<E>
+            + ZetaRead<E>
+            + RiceRead<E>
+            + ZetaReadParam<E>
+            + DeltaReadParam<E>
+            + GammaReadParam<E>
+            + MinimalBinaryRead<E>,
+    {
+        VarIntCodec::decode(reader)
+    }
+
+    #[inline(always)]
+    fn bit_len(value: u64, _params: ()) -> usize {
+        varint_bit_len(value)
+    }
+
+    #[inline(always)]
+    fn skip<R2>(reader: &mut R2, _params: ()) -> Result<usize, Box<dyn Error>>
+    where
+        R2: for<'a> GammaRead<E>
+            + DeltaRead<E>
+            + ExpGolombRead<E>
+            + ZetaRead<E>
+            + RiceRead<E>
+            + ZetaReadParam<E>
+            + DeltaReadParam<E>
+            + GammaReadParam<E>
+            + MinimalBinaryRead<E>,
+    {
+        VarIntCodec::skip(reader)
+    }
+}
+
+/// Bit length of `value`'s LEB128 encoding: one byte per 7-bit group, at
+/// least one group even for `value == 0`. Shared by [`VarIntCodec`] and
+/// [`Leb128Codec`].
+#[inline(always)]
+fn varint_bit_len(value: u64) -> usize {
+    let bits_needed = 64 - value.leading_zeros() as usize;
+    bits_needed.div_ceil(7).max(1) * 8
+}
+
+impl VarIntCodec {
+    /// Maximum number of 7-bit groups a `u64` can need (`ceil(64 / 7)`); used
+    /// to reject overlong encodings that would overflow on decode.
+    const MAX_GROUPS: u32 = 10;
+
+    #[inline(always)]
+    fn write_byte<E: Endianness, W: BitWrite<E>>(
+        writer: &mut W,
+        byte: u8,
+    ) -> Result<usize, Box<dyn Error>> {
+        Ok(writer.write_bits(byte as u64, 8)?)
+    }
+
+    #[inline(always)]
+    fn read_byte<E: Endianness, R: BitRead<E>>(reader: &mut R) -> Result<u8, Box<dyn Error>> {
+        Ok(reader.read_bits(8)? as u8)
+    }
+
+    /// Encodes a value using LEB128.
+    pub fn encode<E: Endianness, W: BitWrite<E>>(
+        writer: &mut W,
+        value: u64,
+    ) -> Result<usize, Box<dyn Error>> {
+        let mut bits = 0;
+        let mut remainder = value;
+        loop {
+            let mut byte = (remainder & 0x7f) as u8;
+            remainder >>= 7;
+            if remainder != 0 {
+                byte |= 0x80;
+            }
+            bits += Self::write_byte(writer, byte)?;
+            if remainder == 0 {
+                break;
+            }
+        }
+        Ok(bits)
+    }
+
+    /// Decodes a value encoded by [`VarIntCodec::encode`].
+    ///
+    /// Returns an error if all [`Self::MAX_GROUPS`] bytes read carry the
+    /// continuation bit, i.e. the encoding runs past the longest a `u64` needs.
+    pub fn decode<E: Endianness, R: BitRead<E>>(reader: &mut R) -> Result<u64, Box<dyn Error>> {
+        let mut value: u64 = 0;
+        for group in 0..Self::MAX_GROUPS {
+            let byte = Self::read_byte(reader)?;
+            value |= ((byte & 0x7f) as u64) << (group * 7);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("malformed LEB128 value: overlong encoding".into())
+    }
+
+    /// Skips one value encoded by [`VarIntCodec::encode`]: reads each
+    /// continuation-tagged byte and stops at the first one without the
+    /// continuation bit set, without assembling the group bits into a value.
+    ///
+    /// Returns an error under the same overlong-encoding condition as
+    /// [`VarIntCodec::decode`].
+    fn skip<E: Endianness, R: BitRead<E>>(reader: &mut R) -> Result<usize, Box<dyn Error>> {
+        for group in 0..Self::MAX_GROUPS {
+            let byte = Self::read_byte(reader)?;
+            if byte & 0x80 == 0 {
+                return Ok((group as usize + 1) * 8);
+            }
+        }
+        Err("malformed LEB128 value: overlong encoding".into())
+    }
+}
+
+/// Leb128Codec: the same byte-aligned base-128 encoding as [`VarIntCodec`],
+/// under the name most callers will search for.
+///
+/// `VarIntCodec` already *is* LEB128 (see its doc comment), so this codec
+/// simply forwards to it rather than duplicating the encode/decode loop —
+/// pick whichever name reads better at the call site, the wire format is
+/// identical.
+pub struct Leb128Codec;
+
+impl<E: Endianness, W: BitWrite<E>> Codec<E, W> for Leb128Codec {
+    type Params = ();
+
+    #[inline(always)]
+    fn encode(writer: &mut W, value: u64, _params: ()) -> Result<usize, Box<dyn Error>> {
+        VarIntCodec::encode(writer, value)
+    }
+
+    #[inline(always)]
+    fn decode<R2>(reader: &mut R2, _params: ()) -> Result<u64, Box<dyn Error>>
+    where
+        R2: for<'a> GammaRead<E>
+            + DeltaRead<E>
+            + ExpGolombRead<E>
+            + ZetaRead<E>
+            + RiceRead<E>
+            + ZetaReadParam<E>
+            + DeltaReadParam<E>
+            + GammaReadParam<E>
+            + MinimalBinaryRead<E>,
+    {
+        VarIntCodec::decode(reader)
+    }
+
+    #[inline(always)]
+    fn bit_len(value: u64, _params: ()) -> usize {
+        varint_bit_len(value)
+    }
+
+    #[inline(always)]
+    fn skip<R2>(reader: &mut R2, _params: ()) -> Result<usize, Box<dyn Error>>
+    where
+        R2: for<'a> GammaRead<E>
+            + DeltaRead<E>
+            + ExpGolombRead<E>
+            + ZetaRead<E>
+            + RiceRead<E>
+            + ZetaReadParam<E>
+            + DeltaReadParam<E>
+            + GammaReadParam<E>
+            + MinimalBinaryRead<E>,
+    {
+        VarIntCodec::skip(reader)
+    }
+}
+
+/// Per-bucket canonical code length table produced by [`HuffmanCodec::train`].
+///
+/// Values are bucketed by their number of significant bits (`0` is its own
+/// bucket, `64` the bucket for values with the top bit set); `lengths[bucket]`
+/// is the canonical Huffman code length for that bucket, or `0` if the bucket
+/// never occurred in the training input.
+pub type HuffmanLengths = [u8; 65];
+
+/// HuffmanCodec: canonical Huffman coding trained on the input.
+///
+/// Rather than building a code over the full 64-bit value space, values are
+/// first bucketed by their number of significant bits (deflate-style); a
+/// canonical Huffman code is trained over the resulting (at most 65-symbol)
+/// bucket distribution via [`HuffmanCodec::train`], and each value is encoded
+/// as its bucket's codeword followed by its raw mantissa bits (the bits below
+/// the implicit leading one). This keeps the stored table small even for
+/// large or sparse value alphabets, while still reaching near-entropy-optimal
+/// compression for columns dominated by a handful of magnitudes.
+///
+/// Unlike the other codecs here, `Params` is trained rather than chosen by
+/// the caller outright: call [`HuffmanCodec::train`] over the data first,
+/// then pass the resulting table to `from_with_param`.
+pub struct HuffmanCodec;
+
+impl<E: Endianness, W: BitWrite<E>> Codec<E, W> for HuffmanCodec {
+    type Params = HuffmanLengths;
+
+    #[inline(always)]
+    fn encode(writer: &mut W, value: u64, lengths: HuffmanLengths) -> Result<usize, Box<dyn Error>> {
+        HuffmanCodec::encode(writer, value, lengths)
+    }
+
+    #[inline(always)]
+    fn decode<R2>(reader: &mut R2, lengths: HuffmanLengths) -> Result<u64, Box<dyn Error>>
+    where
+        R2: for<'a> GammaRead<E>
+            + DeltaRead<E>
+            + ExpGolombRead<E>
+            + ZetaRead<E>
+            + RiceRead<E>
+            + ZetaReadParam<E>
+            + DeltaReadParam<E>
+            + GammaReadParam<E>
+            + MinimalBinaryRead<E>,
+    {
+        HuffmanCodec::decode(reader, lengths)
+    }
+
+    #[inline(always)]
+    fn bit_len(value: u64, lengths: HuffmanLengths) -> usize {
+        let bucket = Self::bucket(value);
+        lengths[bucket] as usize + bucket.saturating_sub(1)
+    }
+
+    #[inline(always)]
+    fn skip<R2>(reader: &mut R2, lengths: HuffmanLengths) -> Result<usize, Box<dyn Error>>
+    where
+        R2: for<'a> GammaRead<E>
+            + DeltaRead<E>
+            + ExpGolombRead<E>
+            + ZetaRead<E>
+            + RiceRead<E>
+            + ZetaReadParam<E>
+            + DeltaReadParam<E>
+            + GammaReadParam<E>
+            + MinimalBinaryRead<E>,
+    {
+        HuffmanCodec::skip(reader, lengths)
+    }
+}
+
+impl HuffmanCodec {
+    /// Returns the bucket (number of significant bits) for `value`; `0` is its
+    /// own bucket, and `64` (values with the top bit set, i.e. `value >=
+    /// 2^63`) is its own bucket too, so the full `u64` range maps to `0..=64`.
+    #[inline(always)]
+    fn bucket(value: u64) -> usize {
+        64 - value.leading_zeros() as usize
+    }
+
+    /// Trains a canonical Huffman code over the bucket distribution of `input`.
+    pub fn train(input: &[u64]) -> HuffmanLengths {
+        let mut freq = [0u64; 65];
+        for &v in input {
+            freq[Self::bucket(v)] += 1;
+        }
+        Self::lengths_from_frequencies(&freq)
+    }
+
+    /// Runs the classic two-at-a-time weight merge to derive canonical code
+    /// lengths from bucket frequencies, without needing to materialize the
+    /// Huffman tree itself.
+    fn lengths_from_frequencies(freq: &[u64; 65]) -> HuffmanLengths {
+        use std::cmp::Reverse;
+        use std::collections::BinaryHeap;
+
+        struct Node {
+            parent: Option<usize>,
+        }
+
+        let mut arena: Vec<Node> = Vec::new();
+        let mut heap: BinaryHeap<Reverse<(u64, usize)>> = BinaryHeap::new();
+        let mut leaf_of_bucket = [usize::MAX; 65];
+
+        for (bucket, &f) in freq.iter().enumerate() {
+            if f > 0 {
+                let idx = arena.len();
+                arena.push(Node { parent: None });
+                leaf_of_bucket[bucket] = idx;
+                heap.push(Reverse((f, idx)));
+            }
+        }
+
+        let mut lengths = [0u8; 65];
+
+        // A single distinct bucket still needs a (trivial) 1-bit codeword.
+        if heap.len() <= 1 {
+            if let Some(Reverse((_, idx))) = heap.pop() {
+                if let Some(bucket) = leaf_of_bucket.iter().position(|&li| li == idx) {
+                    lengths[bucket] = 1;
+                }
+            }
+            return lengths;
+        }
+
+        while heap.len() > 1 {
+            let Reverse((w1, i1)) = heap.pop().unwrap();
+            let Reverse((w2, i2)) = heap.pop().unwrap();
+            let parent_idx = arena.len();
+            arena.push(Node { parent: None });
+            arena[i1].parent = Some(parent_idx);
+            arena[i2].parent = Some(parent_idx);
+            heap.push(Reverse((w1 + w2, parent_idx)));
+        }
+
+        for (bucket, &idx) in leaf_of_bucket.iter().enumerate() {
+            if idx == usize::MAX {
+                continue;
+            }
+            let mut depth = 0u8;
+            let mut cur = idx;
+            while let Some(p) = arena[cur].parent {
+                depth += 1;
+                cur = p;
+            }
+            lengths[bucket] = depth;
+        }
+        lengths
+    }
+
+    /// Assigns canonical codes to each bucket from its code length, by
+    /// ordering buckets by `(length, bucket)` and walking codes upward,
+    /// left-shifting whenever the length grows.
+    fn canonical_codes(lengths: &HuffmanLengths) -> [(u32, u8); 65] {
+        let mut buckets: Vec<usize> = (0..65).filter(|&b| lengths[b] > 0).collect();
+        buckets.sort_by_key(|&b| (lengths[b], b));
+
+        let mut codes = [(0u32, 0u8); 65];
+        let mut code: u32 = 0;
+        let mut prev_len: u8 = 0;
+        for bucket in buckets {
+            let len = lengths[bucket];
+            code <<= len - prev_len;
+            codes[bucket] = (code, len);
+            code += 1;
+            prev_len = len;
+        }
+        codes
+    }
+
+    /// Encodes a value using the canonical Huffman table `lengths`.
+    pub fn encode<E: Endianness, W: BitWrite<E>>(
+        writer: &mut W,
+        value: u64,
+        lengths: HuffmanLengths,
+    ) -> Result<usize, Box<dyn Error>> {
+        let bucket = Self::bucket(value);
+        let (code, len) = Self::canonical_codes(&lengths)[bucket];
+        // One bit at a time, most significant first, so `decode`'s bit-by-bit
+        // accumulation sees the codeword in the same order on either endianness.
+        let mut bits = 0;
+        for i in (0..len as usize).rev() {
+            bits += writer.write_bits(((code >> i) & 1) as u64, 1)?;
+        }
+
+        if bucket > 0 {
+            let mantissa_len = bucket - 1;
+            let mantissa = value - (1u64 << mantissa_len);
+            bits += writer.write_bits(mantissa, mantissa_len)?;
+        }
+        Ok(bits)
+    }
+
+    /// Decodes a value using the canonical Huffman table `lengths`.
+    pub fn decode<E: Endianness, R: BitRead<E>>(
+        reader: &mut R,
+        lengths: HuffmanLengths,
+    ) -> Result<u64, Box<dyn Error>> {
+        let codes = Self::canonical_codes(&lengths);
+
+        let mut acc: u32 = 0;
+        let mut len: u8 = 0;
+        let bucket = loop {
+            acc = (acc << 1) | reader.read_bits(1)? as u32;
+            len += 1;
+            if let Some(b) = (0..65).find(|&b| lengths[b] == len && codes[b].0 == acc) {
+                break b;
+            }
+            if len > 64 {
+                return Err("malformed Huffman code: no matching codeword".into());
+            }
+        };
+
+        if bucket == 0 {
+            Ok(0)
+        } else {
+            let mantissa_len = bucket - 1;
+            let mantissa = reader.read_bits(mantissa_len)?;
+            Ok((1u64 << mantissa_len) | mantissa)
+        }
+    }
+
+    /// Skips one value encoded by [`HuffmanCodec::encode`]. The prefix code
+    /// still has to be matched bit by bit to learn the bucket (there's no
+    /// cheaper way to know a Huffman codeword's length than walking it), but
+    /// the mantissa bits that follow are skipped rather than reconstructed
+    /// into a value.
+    fn skip<E: Endianness, R: BitRead<E>>(
+        reader: &mut R,
+        lengths: HuffmanLengths,
+    ) -> Result<usize, Box<dyn Error>> {
+        let codes = Self::canonical_codes(&lengths);
+
+        let mut acc: u32 = 0;
+        let mut len: u8 = 0;
+        let bucket = loop {
+            acc = (acc << 1) | reader.read_bits(1)? as u32;
+            len += 1;
+            if let Some(b) = (0..65).find(|&b| lengths[b] == len && codes[b].0 == acc) {
+                break b;
+            }
+            if len > 64 {
+                return Err("malformed Huffman code: no matching codeword".into());
+            }
+        };
+
+        let mantissa_len = bucket.saturating_sub(1);
+        reader.skip_bits(mantissa_len)?;
+        Ok(len as usize + mantissa_len)
+    }
+
+    /// Serializes `lengths` into the compact canonical form a decoder needs
+    /// to reconstruct [`canonical_codes`](Self::canonical_codes): the
+    /// maximum code length, a count of codewords per length, then the
+    /// symbols (bucket indices) in canonical `(length, bucket)` order. This
+    /// is far smaller than the raw 65-byte [`HuffmanLengths`] table whenever
+    /// the trained alphabet is sparse, which keeps a persisted vector
+    /// self-describing without inflating its header.
+    pub fn serialize_table(lengths: &HuffmanLengths) -> Vec<u8> {
+        let max_len = lengths.iter().copied().max().unwrap_or(0);
+
+        let mut counts = vec![0u8; max_len as usize];
+        let mut buckets: Vec<usize> = (0..65).filter(|&b| lengths[b] > 0).collect();
+        buckets.sort_by_key(|&b| (lengths[b], b));
+        for &b in &buckets {
+            counts[lengths[b] as usize - 1] += 1;
+        }
+
+        let mut out = Vec::with_capacity(1 + counts.len() + buckets.len());
+        out.push(max_len);
+        out.extend_from_slice(&counts);
+        out.extend(buckets.iter().map(|&b| b as u8));
+        out
+    }
+
+    /// Inverts [`HuffmanCodec::serialize_table`], rebuilding a full
+    /// [`HuffmanLengths`] table from its compact form.
+    pub fn deserialize_table(bytes: &[u8]) -> Result<HuffmanLengths, Box<dyn Error>> {
+        let &max_len = bytes.first().ok_or("empty Huffman table")?;
+        let counts = bytes
+            .get(1..1 + max_len as usize)
+            .ok_or("truncated Huffman table: length counts")?;
+        let symbols = &bytes[1 + max_len as usize..];
+
+        let mut lengths = [0u8; 65];
+        let mut pos = 0usize;
+        for (i, &count) in counts.iter().enumerate() {
+            let len = (i + 1) as u8;
+            for _ in 0..count {
+                let &bucket = symbols.get(pos).ok_or("truncated Huffman table: symbols")?;
+                if bucket as usize >= lengths.len() {
+                    return Err("malformed Huffman table: bucket index out of range".into());
+                }
+                lengths[bucket as usize] = len;
+                pos += 1;
+            }
+        }
+        Ok(lengths)
+    }
+}
@@ -0,0 +1,191 @@
+//! # Delta + zigzag preprocessing transform
+//!
+//! Many codecs (Gamma, Delta, Rice…) only compress well when the values they
+//! see are small, but real sequences — sorted timestamps, descending ranks,
+//! anything with small but arbitrarily-signed gaps between consecutive
+//! elements — can have large absolute values even though the *gaps* between
+//! them are tiny. [`GenericDeltaTransform`] wraps a codec `C` with a
+//! first-order differencing step so the inner codec only ever sees those
+//! small gaps: every sampled block stores its first element verbatim
+//! (reusing the existing sample array, same as
+//! [`MonotoneIntVec`](crate::monotone::MonotoneIntVec)) and every other
+//! element as `v[i] - v[i-1]`, zigzag-folded into an unsigned integer so
+//! that small positive *and* negative gaps both map to small unsigned
+//! values:
+//!
+//! ```text
+//! zz(d)   = (d << 1) ^ (d >> 63)              // arithmetic shift
+//! unzz(z) = (z >> 1) ^ -(z & 1)
+//! ```
+//!
+//! Unlike [`MonotoneIntVec`](crate::monotone::MonotoneIntVec), the input need
+//! not be sorted — the transform folds arbitrary signed gaps, not just
+//! non-decreasing ones.
+//!
+//! `get(i)` reconstructs a value by seeding the running total from the
+//! nearest sample and wrapping-summing forward, so random access stays
+//! `O(sampling)`: reconstruction never crosses a sample boundary.
+//!
+//! [`GenericDeltaTransform::from`] takes no codec parameter, so only codecs
+//! whose `Params` is `()` (such as `GammaCodec` or `DeltaCodec`) can be
+//! wrapped; parametric codecs like `RiceCodec` cannot.
+//!
+//! Like [`IntVec`](crate::intvec::IntVec) itself, the transform is generic
+//! over endianness; [`DeltaTransform`]/[`BEDeltaTransform`] are the
+//! little-endian/big-endian instantiations, mirroring
+//! [`LEIntVec`](crate::intvec::LEIntVec)/[`BEIntVec`](crate::intvec::BEIntVec).
+
+use crate::codecs::Codec;
+use crate::intvec::IntVec;
+use dsi_bitstream::prelude::*;
+use std::error::Error;
+
+/// Zigzag-folds a signed delta into an unsigned integer: small deltas of
+/// either sign map to small unsigned values.
+#[inline(always)]
+fn zigzag_encode(delta: i64) -> u64 {
+    ((delta << 1) ^ (delta >> 63)) as u64
+}
+
+/// Inverts [`zigzag_encode`].
+#[inline(always)]
+fn zigzag_decode(zz: u64) -> i64 {
+    ((zz >> 1) as i64) ^ -((zz & 1) as i64)
+}
+
+/// A vector of integers stored as per-block absolute bases plus zigzag-folded
+/// forward deltas, wrapping a codec `C` with `O(sampling)` random access
+/// (constructible only for codecs whose `Params` is `()`).
+///
+/// Generic over endianness `E`/`W`; use the [`DeltaTransform`] (little-endian)
+/// or [`BEDeltaTransform`] (big-endian) aliases rather than naming this type
+/// directly.
+pub struct GenericDeltaTransform<E: Endianness, W: BitWrite<E>, C: Codec<E, W>>
+where
+    C::Params: Copy,
+{
+    inner: IntVec<E, W, C>,
+}
+
+impl<E, W, C> GenericDeltaTransform<E, W, C>
+where
+    E: Endianness,
+    W: BitWrite<E>,
+    C: Codec<E, W, Params = ()>,
+    IntVec<E, W, C>: IntVecFrom<E, W, C>,
+{
+    /// Builds a [`GenericDeltaTransform`] from `input`, sampling every
+    /// `k`-th element and storing a full value at every sample boundary.
+    pub fn from(input: &[u64], k: usize) -> Result<Self, Box<dyn Error>> {
+        let mut transformed = Vec::with_capacity(input.len());
+        for (i, &v) in input.iter().enumerate() {
+            if i % k == 0 {
+                transformed.push(v);
+            } else {
+                let delta = (v as i64).wrapping_sub(input[i - 1] as i64);
+                transformed.push(zigzag_encode(delta));
+            }
+        }
+
+        Ok(GenericDeltaTransform {
+            inner: IntVec::<E, W, C>::from_values(&transformed, k)?,
+        })
+    }
+}
+
+impl<E, W, C> GenericDeltaTransform<E, W, C>
+where
+    E: Endianness,
+    W: BitWrite<E>,
+    C: Codec<E, W>,
+    C::Params: Copy,
+    IntVec<E, W, C>: IntVecDecodeRange<E, W, C>,
+{
+    /// Retrieves the value at the given index, reconstructing it by summing
+    /// zigzag-decoded deltas forward from the nearest sampled block base.
+    /// Panics if out of bounds.
+    pub fn get(&self, index: usize) -> u64 {
+        let k = self.inner.k;
+        let block_start = (index / k) * k;
+
+        let mut stored = self.inner.decode_range_values(block_start, index + 1).into_iter();
+        let mut total = stored.next().expect("block is non-empty by construction");
+        for zz in stored {
+            total = total.wrapping_add(zigzag_decode(zz) as u64);
+        }
+        total
+    }
+
+    /// Returns the number of integers stored in the vector.
+    pub fn len(&self) -> usize {
+        self.inner.len
+    }
+
+    /// Returns `true` if the vector holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.inner.len == 0
+    }
+}
+
+/// Bridges [`GenericDeltaTransform::from`] to the endianness-specific
+/// `IntVec::from` constructors, which only exist (via a blanket impl over
+/// `Params = ()` codecs) on the [`LEIntVec`](crate::intvec::LEIntVec)/
+/// [`BEIntVec`](crate::intvec::BEIntVec) aliases rather than on generic
+/// `IntVec<E, W, C>` itself.
+pub trait IntVecFrom<E: Endianness, W: BitWrite<E>, C: Codec<E, W, Params = ()>> {
+    fn from_values(input: &[u64], k: usize) -> Result<IntVec<E, W, C>, Box<dyn Error>>;
+}
+
+impl<C: Codec<LE, LeWriter, Params = ()>> IntVecFrom<LE, LeWriter, C> for IntVec<LE, LeWriter, C> {
+    fn from_values(input: &[u64], k: usize) -> Result<IntVec<LE, LeWriter, C>, Box<dyn Error>> {
+        crate::intvec::LEIntVec::<C>::from(input, k)
+    }
+}
+
+impl<C: Codec<BE, BeWriter, Params = ()>> IntVecFrom<BE, BeWriter, C> for IntVec<BE, BeWriter, C> {
+    fn from_values(input: &[u64], k: usize) -> Result<IntVec<BE, BeWriter, C>, Box<dyn Error>> {
+        crate::intvec::BEIntVec::<C>::from(input, k)
+    }
+}
+
+/// Bridges `get` to the endianness-specific `decode_range`, which (like
+/// [`IntVecFrom::from_values`]) is only defined on the
+/// [`LEIntVec`](crate::intvec::LEIntVec)/[`BEIntVec`](crate::intvec::BEIntVec)
+/// aliases rather than on generic `IntVec<E, W, C>` itself.
+pub trait IntVecDecodeRange<E: Endianness, W: BitWrite<E>, C: Codec<E, W>>
+where
+    C::Params: Copy,
+{
+    fn decode_range_values(&self, start: usize, end: usize) -> Vec<u64>;
+}
+
+impl<C: Codec<LE, LeWriter>> IntVecDecodeRange<LE, LeWriter, C> for IntVec<LE, LeWriter, C>
+where
+    C::Params: Copy,
+{
+    fn decode_range_values(&self, start: usize, end: usize) -> Vec<u64> {
+        self.decode_range(start, end)
+    }
+}
+
+impl<C: Codec<BE, BeWriter>> IntVecDecodeRange<BE, BeWriter, C> for IntVec<BE, BeWriter, C>
+where
+    C::Params: Copy,
+{
+    fn decode_range_values(&self, start: usize, end: usize) -> Vec<u64> {
+        self.decode_range(start, end)
+    }
+}
+
+type LeWriter = dsi_bitstream::impls::BufBitWriter<LE, dsi_bitstream::impls::MemWordWriterVec<u64, Vec<u64>>>;
+type BeWriter = dsi_bitstream::impls::BufBitWriter<BE, dsi_bitstream::impls::MemWordWriterVec<u64, Vec<u64>>>;
+
+/// Little-endian delta+zigzag transform, built over [`LEIntVec`](crate::intvec::LEIntVec).
+pub type DeltaTransform<C> = GenericDeltaTransform<LE, LeWriter, C>;
+
+/// Big-endian delta+zigzag transform, built over [`BEIntVec`](crate::intvec::BEIntVec) —
+/// useful when the stored bitstream needs to interoperate with other
+/// big-endian `dsi-bitstream`-based formats, mirroring why
+/// [`BEIntVec`](crate::intvec::BEIntVec) exists alongside
+/// [`LEIntVec`](crate::intvec::LEIntVec).
+pub type BEDeltaTransform<C> = GenericDeltaTransform<BE, BeWriter, C>;
@@ -0,0 +1,307 @@
+//! # Runtime (object-safe) codec dispatch
+//!
+//! Every codec elsewhere in the crate is selected as a *type* parameter —
+//! `IntVec<E, W, C>`, `AutoIntVec`'s variants, `CodecChoice` from
+//! [`codec_select`](crate::codec_select) — which means a single container
+//! can't hold vectors built with different codecs chosen at runtime, since
+//! [`Codec::decode`]'s bound (one reader implementing every `*Read` trait at
+//! once) rules out a `dyn Codec` trait object. [`DynCodec`] sidesteps this
+//! the same way [`codec_select::CodecChoice`](crate::codec_select::CodecChoice)
+//! does for the fixed codecs, but as a single enum spanning every codec in
+//! [`codecs`](crate::codecs): it carries each codec's runtime parameter (or
+//! none, for parameterless codecs) as plain data, and
+//! [`encode_dyn`](DynCodec::encode_dyn)/[`decode_dyn`](DynCodec::decode_dyn)/
+//! [`bit_len_dyn`](DynCodec::bit_len_dyn) match on the variant and call the
+//! corresponding monomorphic `Codec` method. [`DynCodec::to_bytes`]/
+//! [`DynCodec::from_bytes`] let the chosen variant itself be persisted
+//! alongside the encoded data, so a reader doesn't need to know the codec
+//! ahead of time.
+//!
+//! Like [`auto`](crate::auto) and [`codec_select`](crate::codec_select),
+//! this is little-endian only; a big-endian counterpart would be the same
+//! enum over `BE` bounds.
+
+use crate::codecs::{
+    Codec, CompactCodec, DeltaCodec, ExpGolombCodec, GammaCodec, HuffmanCodec, HuffmanLengths,
+    Leb128Codec, MinimalBinaryCodec, ParamDeltaCodec, ParamGammaCodec, ParamZetaCodec, RiceCodec,
+    StreamVByteCodec, VarIntCodec, ZetaCodec,
+};
+use dsi_bitstream::impls::{BufBitWriter, MemWordWriterVec};
+use dsi_bitstream::prelude::*;
+use std::error::Error;
+
+/// The writer type used purely to select `Codec<LE, _>` impls in
+/// [`DynCodec::bit_len_dyn`], which never actually writes through it.
+type Writer = BufBitWriter<LE, MemWordWriterVec<u64, Vec<u64>>>;
+
+/// A codec choice carried as a runtime value rather than a type parameter,
+/// so a container can hold vectors compressed with different codecs picked
+/// at runtime (for example by [`select_best_codec`](crate::codec_select::select_best_codec)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynCodec {
+    Gamma,
+    Delta,
+    ExpGolomb(usize),
+    Zeta(u64),
+    Rice(usize),
+    MinimalBinary(u64),
+    ParamZeta { use_table: bool },
+    ParamDelta { use_delta_table: bool, use_gamma_table: bool },
+    ParamGamma { use_table: bool },
+    Compact,
+    StreamVByte,
+    VarInt,
+    Leb128,
+    Huffman(HuffmanLengths),
+}
+
+impl DynCodec {
+    /// Encodes `value` through `writer` with whichever codec `self` selects.
+    pub fn encode_dyn<W>(&self, writer: &mut W, value: u64) -> Result<usize, Box<dyn Error>>
+    where
+        W: BitWrite<LE>
+            + MinimalBinaryWrite<LE>
+            + GammaWrite<LE>
+            + DeltaWrite<LE>
+            + ExpGolombWrite<LE>
+            + ZetaWrite<LE>
+            + RiceWrite<LE>
+            + ZetaWriteParam<LE>
+            + DeltaWriteParam<LE>
+            + GammaWriteParam<LE>,
+    {
+        match *self {
+            DynCodec::Gamma => <GammaCodec as Codec<LE, W>>::encode(writer, value, ()),
+            DynCodec::Delta => <DeltaCodec as Codec<LE, W>>::encode(writer, value, ()),
+            DynCodec::ExpGolomb(k) => <ExpGolombCodec as Codec<LE, W>>::encode(writer, value, k),
+            DynCodec::Zeta(k) => <ZetaCodec as Codec<LE, W>>::encode(writer, value, k),
+            DynCodec::Rice(log2_b) => <RiceCodec as Codec<LE, W>>::encode(writer, value, log2_b),
+            DynCodec::MinimalBinary(upper_bound) => {
+                <MinimalBinaryCodec as Codec<LE, W>>::encode(writer, value, upper_bound)
+            }
+            DynCodec::ParamZeta { use_table: true } => {
+                <ParamZetaCodec<true> as Codec<LE, W>>::encode(writer, value, ())
+            }
+            DynCodec::ParamZeta { use_table: false } => {
+                <ParamZetaCodec<false> as Codec<LE, W>>::encode(writer, value, ())
+            }
+            DynCodec::ParamDelta { use_delta_table: true, use_gamma_table: true } => {
+                <ParamDeltaCodec<true, true> as Codec<LE, W>>::encode(writer, value, ())
+            }
+            DynCodec::ParamDelta { use_delta_table: true, use_gamma_table: false } => {
+                <ParamDeltaCodec<true, false> as Codec<LE, W>>::encode(writer, value, ())
+            }
+            DynCodec::ParamDelta { use_delta_table: false, use_gamma_table: true } => {
+                <ParamDeltaCodec<false, true> as Codec<LE, W>>::encode(writer, value, ())
+            }
+            DynCodec::ParamDelta { use_delta_table: false, use_gamma_table: false } => {
+                <ParamDeltaCodec<false, false> as Codec<LE, W>>::encode(writer, value, ())
+            }
+            DynCodec::ParamGamma { use_table: true } => {
+                <ParamGammaCodec<true> as Codec<LE, W>>::encode(writer, value, ())
+            }
+            DynCodec::ParamGamma { use_table: false } => {
+                <ParamGammaCodec<false> as Codec<LE, W>>::encode(writer, value, ())
+            }
+            DynCodec::Compact => <CompactCodec as Codec<LE, W>>::encode(writer, value, ()),
+            DynCodec::StreamVByte => <StreamVByteCodec as Codec<LE, W>>::encode(writer, value, ()),
+            DynCodec::VarInt => <VarIntCodec as Codec<LE, W>>::encode(writer, value, ()),
+            DynCodec::Leb128 => <Leb128Codec as Codec<LE, W>>::encode(writer, value, ()),
+            DynCodec::Huffman(lengths) => {
+                <HuffmanCodec as Codec<LE, W>>::encode(writer, value, lengths)
+            }
+        }
+    }
+
+    /// Decodes one value from `reader` with whichever codec `self` selects.
+    pub fn decode_dyn<R>(&self, reader: &mut R) -> Result<u64, Box<dyn Error>>
+    where
+        R: GammaRead<LE>
+            + DeltaRead<LE>
+            + ExpGolombRead<LE>
+            + ZetaRead<LE>
+            + RiceRead<LE>
+            + ZetaReadParam<LE>
+            + DeltaReadParam<LE>
+            + GammaReadParam<LE>
+            + MinimalBinaryRead<LE>,
+    {
+        match *self {
+            DynCodec::Gamma => <GammaCodec as Codec<LE, Writer>>::decode(reader, ()),
+            DynCodec::Delta => <DeltaCodec as Codec<LE, Writer>>::decode(reader, ()),
+            DynCodec::ExpGolomb(k) => <ExpGolombCodec as Codec<LE, Writer>>::decode(reader, k),
+            DynCodec::Zeta(k) => <ZetaCodec as Codec<LE, Writer>>::decode(reader, k),
+            DynCodec::Rice(log2_b) => <RiceCodec as Codec<LE, Writer>>::decode(reader, log2_b),
+            DynCodec::MinimalBinary(upper_bound) => {
+                <MinimalBinaryCodec as Codec<LE, Writer>>::decode(reader, upper_bound)
+            }
+            DynCodec::ParamZeta { use_table: true } => {
+                <ParamZetaCodec<true> as Codec<LE, Writer>>::decode(reader, ())
+            }
+            DynCodec::ParamZeta { use_table: false } => {
+                <ParamZetaCodec<false> as Codec<LE, Writer>>::decode(reader, ())
+            }
+            DynCodec::ParamDelta { use_delta_table: true, use_gamma_table: true } => {
+                <ParamDeltaCodec<true, true> as Codec<LE, Writer>>::decode(reader, ())
+            }
+            DynCodec::ParamDelta { use_delta_table: true, use_gamma_table: false } => {
+                <ParamDeltaCodec<true, false> as Codec<LE, Writer>>::decode(reader, ())
+            }
+            DynCodec::ParamDelta { use_delta_table: false, use_gamma_table: true } => {
+                <ParamDeltaCodec<false, true> as Codec<LE, Writer>>::decode(reader, ())
+            }
+            DynCodec::ParamDelta { use_delta_table: false, use_gamma_table: false } => {
+                <ParamDeltaCodec<false, false> as Codec<LE, Writer>>::decode(reader, ())
+            }
+            DynCodec::ParamGamma { use_table: true } => {
+                <ParamGammaCodec<true> as Codec<LE, Writer>>::decode(reader, ())
+            }
+            DynCodec::ParamGamma { use_table: false } => {
+                <ParamGammaCodec<false> as Codec<LE, Writer>>::decode(reader, ())
+            }
+            DynCodec::Compact => <CompactCodec as Codec<LE, Writer>>::decode(reader, ()),
+            DynCodec::StreamVByte => <StreamVByteCodec as Codec<LE, Writer>>::decode(reader, ()),
+            DynCodec::VarInt => <VarIntCodec as Codec<LE, Writer>>::decode(reader, ()),
+            DynCodec::Leb128 => <Leb128Codec as Codec<LE, Writer>>::decode(reader, ()),
+            DynCodec::Huffman(lengths) => {
+                <HuffmanCodec as Codec<LE, Writer>>::decode(reader, lengths)
+            }
+        }
+    }
+
+    /// Returns the number of bits `encode_dyn` would write for `value`,
+    /// without writing anything; mirrors [`Codec::bit_len`].
+    pub fn bit_len_dyn(&self, value: u64) -> usize {
+        match *self {
+            DynCodec::Gamma => <GammaCodec as Codec<LE, Writer>>::bit_len(value, ()),
+            DynCodec::Delta => <DeltaCodec as Codec<LE, Writer>>::bit_len(value, ()),
+            DynCodec::ExpGolomb(k) => <ExpGolombCodec as Codec<LE, Writer>>::bit_len(value, k),
+            DynCodec::Zeta(k) => <ZetaCodec as Codec<LE, Writer>>::bit_len(value, k),
+            DynCodec::Rice(log2_b) => <RiceCodec as Codec<LE, Writer>>::bit_len(value, log2_b),
+            DynCodec::MinimalBinary(upper_bound) => {
+                <MinimalBinaryCodec as Codec<LE, Writer>>::bit_len(value, upper_bound)
+            }
+            DynCodec::ParamZeta { use_table: true } => {
+                <ParamZetaCodec<true> as Codec<LE, Writer>>::bit_len(value, ())
+            }
+            DynCodec::ParamZeta { use_table: false } => {
+                <ParamZetaCodec<false> as Codec<LE, Writer>>::bit_len(value, ())
+            }
+            DynCodec::ParamDelta { use_delta_table: true, use_gamma_table: true } => {
+                <ParamDeltaCodec<true, true> as Codec<LE, Writer>>::bit_len(value, ())
+            }
+            DynCodec::ParamDelta { use_delta_table: true, use_gamma_table: false } => {
+                <ParamDeltaCodec<true, false> as Codec<LE, Writer>>::bit_len(value, ())
+            }
+            DynCodec::ParamDelta { use_delta_table: false, use_gamma_table: true } => {
+                <ParamDeltaCodec<false, true> as Codec<LE, Writer>>::bit_len(value, ())
+            }
+            DynCodec::ParamDelta { use_delta_table: false, use_gamma_table: false } => {
+                <ParamDeltaCodec<false, false> as Codec<LE, Writer>>::bit_len(value, ())
+            }
+            DynCodec::ParamGamma { use_table: true } => {
+                <ParamGammaCodec<true> as Codec<LE, Writer>>::bit_len(value, ())
+            }
+            DynCodec::ParamGamma { use_table: false } => {
+                <ParamGammaCodec<false> as Codec<LE, Writer>>::bit_len(value, ())
+            }
+            DynCodec::Compact => <CompactCodec as Codec<LE, Writer>>::bit_len(value, ()),
+            DynCodec::StreamVByte => <StreamVByteCodec as Codec<LE, Writer>>::bit_len(value, ()),
+            DynCodec::VarInt => <VarIntCodec as Codec<LE, Writer>>::bit_len(value, ()),
+            DynCodec::Leb128 => <Leb128Codec as Codec<LE, Writer>>::bit_len(value, ()),
+            DynCodec::Huffman(lengths) => {
+                <HuffmanCodec as Codec<LE, Writer>>::bit_len(value, lengths)
+            }
+        }
+    }
+
+    /// Serializes the codec choice itself (not any encoded data) so it can
+    /// be persisted alongside a vector and recovered by [`DynCodec::from_bytes`]
+    /// without the reader needing to know the codec ahead of time.
+    ///
+    /// `ExpGolomb`/`Zeta`/`Rice`'s parameter is stored as a single byte, so
+    /// this fails rather than silently truncating a parameter above 255.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Box<dyn Error>> {
+        match *self {
+            DynCodec::Gamma => Ok(vec![0]),
+            DynCodec::Delta => Ok(vec![1]),
+            DynCodec::ExpGolomb(k) => {
+                let k: u8 = k.try_into().map_err(|_| "ExpGolomb parameter does not fit in a byte")?;
+                Ok(vec![2, k])
+            }
+            DynCodec::Zeta(k) => {
+                let k: u8 = k.try_into().map_err(|_| "Zeta parameter does not fit in a byte")?;
+                Ok(vec![3, k])
+            }
+            DynCodec::Rice(log2_b) => {
+                let log2_b: u8 =
+                    log2_b.try_into().map_err(|_| "Rice parameter does not fit in a byte")?;
+                Ok(vec![4, log2_b])
+            }
+            DynCodec::MinimalBinary(upper_bound) => {
+                let mut out = vec![5];
+                out.extend_from_slice(&upper_bound.to_le_bytes());
+                Ok(out)
+            }
+            DynCodec::ParamZeta { use_table } => Ok(vec![6, use_table as u8]),
+            DynCodec::ParamDelta { use_delta_table, use_gamma_table } => {
+                Ok(vec![7, use_delta_table as u8, use_gamma_table as u8])
+            }
+            DynCodec::ParamGamma { use_table } => Ok(vec![8, use_table as u8]),
+            DynCodec::Compact => Ok(vec![9]),
+            DynCodec::StreamVByte => Ok(vec![10]),
+            DynCodec::VarInt => Ok(vec![11]),
+            DynCodec::Leb128 => Ok(vec![12]),
+            DynCodec::Huffman(lengths) => {
+                let table = HuffmanCodec::serialize_table(&lengths);
+                let mut out = vec![13];
+                out.extend_from_slice(&(table.len() as u16).to_le_bytes());
+                out.extend_from_slice(&table);
+                Ok(out)
+            }
+        }
+    }
+
+    /// Inverts [`DynCodec::to_bytes`], returning the decoded choice and the
+    /// number of bytes consumed from `bytes` (so a caller serializing
+    /// several codecs back to back knows where the next one starts).
+    pub fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), Box<dyn Error>> {
+        let &tag = bytes.first().ok_or("empty DynCodec encoding")?;
+        match tag {
+            0 => Ok((DynCodec::Gamma, 1)),
+            1 => Ok((DynCodec::Delta, 1)),
+            2 => Ok((DynCodec::ExpGolomb(*bytes.get(1).ok_or("truncated ExpGolomb param")? as usize), 2)),
+            3 => Ok((DynCodec::Zeta(*bytes.get(1).ok_or("truncated Zeta param")? as u64), 2)),
+            4 => Ok((DynCodec::Rice(*bytes.get(1).ok_or("truncated Rice param")? as usize), 2)),
+            5 => {
+                let raw = bytes.get(1..9).ok_or("truncated MinimalBinary param")?;
+                Ok((DynCodec::MinimalBinary(u64::from_le_bytes(raw.try_into().unwrap())), 9))
+            }
+            6 => {
+                let use_table = *bytes.get(1).ok_or("truncated ParamZeta flag")? != 0;
+                Ok((DynCodec::ParamZeta { use_table }, 2))
+            }
+            7 => {
+                let use_delta_table = *bytes.get(1).ok_or("truncated ParamDelta flags")? != 0;
+                let use_gamma_table = *bytes.get(2).ok_or("truncated ParamDelta flags")? != 0;
+                Ok((DynCodec::ParamDelta { use_delta_table, use_gamma_table }, 3))
+            }
+            8 => {
+                let use_table = *bytes.get(1).ok_or("truncated ParamGamma flag")? != 0;
+                Ok((DynCodec::ParamGamma { use_table }, 2))
+            }
+            9 => Ok((DynCodec::Compact, 1)),
+            10 => Ok((DynCodec::StreamVByte, 1)),
+            11 => Ok((DynCodec::VarInt, 1)),
+            12 => Ok((DynCodec::Leb128, 1)),
+            13 => {
+                let len_bytes = bytes.get(1..3).ok_or("truncated Huffman table length")?;
+                let len = u16::from_le_bytes(len_bytes.try_into().unwrap()) as usize;
+                let table = bytes.get(3..3 + len).ok_or("truncated Huffman table")?;
+                let lengths = HuffmanCodec::deserialize_table(table)?;
+                Ok((DynCodec::Huffman(lengths), 3 + len))
+            }
+            _ => Err(format!("unknown DynCodec tag {tag}").into()),
+        }
+    }
+}
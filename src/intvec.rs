@@ -309,6 +309,187 @@ where
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
+
+    /// Decodes the half-open range `[start, end)`, seeking only once to the sample
+    /// preceding `start` and decoding forward through the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end > self.len()` or `start > end`.
+    pub fn decode_range(&self, start: usize, end: usize) -> Vec<u64> {
+        let mut out = Vec::with_capacity(end.saturating_sub(start));
+        self.decode_range_into(start, end, &mut out);
+        out
+    }
+
+    /// Like [`decode_range`](Self::decode_range), but decodes into the caller's
+    /// buffer, clearing it first rather than allocating a new one.
+    pub fn decode_range_into(&self, start: usize, end: usize, out: &mut Vec<u64>) {
+        out.clear();
+        assert!(start <= end && end <= self.len, "range out of bounds");
+        if start == end {
+            return;
+        }
+
+        let sample_index = start / self.k;
+        let block_start = sample_index * self.k;
+        let mut reader =
+            BufBitReader::<BE, MemWordReader<u64, &Vec<u64>>>::new(MemWordReader::new(&self.data));
+        reader.set_bit_pos(self.samples[sample_index] as u64).unwrap();
+
+        let param = self.codec_param;
+        for i in block_start..end {
+            let value = C::decode(&mut reader, param).unwrap();
+            if i >= start {
+                out.push(value);
+            }
+        }
+    }
+
+    /// Decompresses the entire vector into the caller's buffer, clearing it first
+    /// rather than allocating a new one. See [`into_vec`](Self::into_vec) for the
+    /// allocating equivalent.
+    pub fn into_vec_into(&self, out: &mut Vec<u64>) {
+        out.clear();
+        out.reserve(self.len);
+        let word_reader = MemWordReader::new(&self.data);
+        let mut reader = BufBitReader::<BE, MemWordReader<u64, &Vec<u64>>>::new(word_reader);
+        for _ in 0..self.len {
+            out.push(C::decode(&mut reader, self.codec_param).unwrap());
+        }
+    }
+
+    /// Retrieves the values at the given `indices`, returned in the same order as
+    /// requested.
+    ///
+    /// Indices are sorted internally so that the bitstream is swept forward once
+    /// per contiguous run of indices sharing (or adjacent within) a sample block,
+    /// reusing a single reader instead of reseeking for every index.
+    pub fn get_many(&self, indices: &[usize]) -> Vec<u64> {
+        if indices.is_empty() {
+            return Vec::new();
+        }
+
+        let mut order: Vec<usize> = (0..indices.len()).collect();
+        order.sort_by_key(|&i| indices[i]);
+
+        let mut result = vec![0u64; indices.len()];
+        let mut reader =
+            BufBitReader::<BE, MemWordReader<u64, &Vec<u64>>>::new(MemWordReader::new(&self.data));
+        let param = self.codec_param;
+        let mut decoded_through: Option<usize> = None;
+
+        for order_idx in order {
+            let index = indices[order_idx];
+            let sample_index = index / self.k;
+            let block_start = sample_index * self.k;
+
+            let resume_from = match decoded_through {
+                Some(pos) if pos < index && pos + 1 >= block_start => pos + 1,
+                _ => {
+                    reader.set_bit_pos(self.samples[sample_index] as u64).unwrap();
+                    block_start
+                }
+            };
+
+            let mut value = 0;
+            for _ in resume_from..=index {
+                value = C::decode(&mut reader, param).unwrap();
+            }
+            result[order_idx] = value;
+            decoded_through = Some(index);
+        }
+
+        result
+    }
+
+    /// Like [`decode_range`](Self::decode_range), but writes into the
+    /// caller's slice instead of allocating, for zero-allocation hot loops.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end > self.len()`, `start > end`, or `out` is shorter than
+    /// `end - start`.
+    pub fn get_range(&self, start: usize, end: usize, out: &mut [u64]) {
+        assert!(start <= end && end <= self.len, "range out of bounds");
+        assert!(out.len() >= end - start, "output buffer too small");
+        if start == end {
+            return;
+        }
+
+        let sample_index = start / self.k;
+        let block_start = sample_index * self.k;
+        let mut reader =
+            BufBitReader::<BE, MemWordReader<u64, &Vec<u64>>>::new(MemWordReader::new(&self.data));
+        reader.set_bit_pos(self.samples[sample_index] as u64).unwrap();
+
+        let param = self.codec_param;
+        let mut out_idx = 0;
+        for i in block_start..end {
+            let value = C::decode(&mut reader, param).unwrap();
+            if i >= start {
+                out[out_idx] = value;
+                out_idx += 1;
+            }
+        }
+    }
+
+    /// Like [`get_many`](Self::get_many), but writes into the caller's
+    /// slice (at each requested index's original position) instead of
+    /// allocating a result vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `indices`.
+    pub fn get_many_into(&self, indices: &[usize], out: &mut [u64]) {
+        assert!(out.len() >= indices.len(), "output buffer too small");
+        if indices.is_empty() {
+            return;
+        }
+
+        let mut order: Vec<usize> = (0..indices.len()).collect();
+        order.sort_by_key(|&i| indices[i]);
+
+        let mut reader =
+            BufBitReader::<BE, MemWordReader<u64, &Vec<u64>>>::new(MemWordReader::new(&self.data));
+        let param = self.codec_param;
+        let mut decoded_through: Option<usize> = None;
+
+        for order_idx in order {
+            let index = indices[order_idx];
+            let sample_index = index / self.k;
+            let block_start = sample_index * self.k;
+
+            let resume_from = match decoded_through {
+                Some(pos) if pos < index && pos + 1 >= block_start => pos + 1,
+                _ => {
+                    reader.set_bit_pos(self.samples[sample_index] as u64).unwrap();
+                    block_start
+                }
+            };
+
+            let mut value = 0;
+            for _ in resume_from..=index {
+                value = C::decode(&mut reader, param).unwrap();
+            }
+            out[order_idx] = value;
+            decoded_through = Some(index);
+        }
+    }
+
+    /// Like [`get_range`](Self::get_range), but appends to a caller-owned
+    /// `Vec<u64>` instead of writing into a pre-sized slice, so the same
+    /// buffer can be reused (and grown on demand) across successive
+    /// dense-range queries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.end > self.len()` or `range.start > range.end`.
+    pub fn get_range_into(&self, range: std::ops::Range<usize>, out: &mut Vec<u64>) {
+        let old_len = out.len();
+        out.resize(old_len + range.len(), 0);
+        self.get_range(range.start, range.end, &mut out[old_len..]);
+    }
 }
 
 /// Convenience constructor for codecs with no extra runtime parameter.
@@ -525,6 +706,187 @@ where
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
+
+    /// Decodes the half-open range `[start, end)`, seeking only once to the sample
+    /// preceding `start` and decoding forward through the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end > self.len()` or `start > end`.
+    pub fn decode_range(&self, start: usize, end: usize) -> Vec<u64> {
+        let mut out = Vec::with_capacity(end.saturating_sub(start));
+        self.decode_range_into(start, end, &mut out);
+        out
+    }
+
+    /// Like [`decode_range`](Self::decode_range), but decodes into the caller's
+    /// buffer, clearing it first rather than allocating a new one.
+    pub fn decode_range_into(&self, start: usize, end: usize, out: &mut Vec<u64>) {
+        out.clear();
+        assert!(start <= end && end <= self.len, "range out of bounds");
+        if start == end {
+            return;
+        }
+
+        let sample_index = start / self.k;
+        let block_start = sample_index * self.k;
+        let mut reader =
+            BufBitReader::<LE, MemWordReader<u64, &Vec<u64>>>::new(MemWordReader::new(&self.data));
+        reader.set_bit_pos(self.samples[sample_index] as u64).unwrap();
+
+        let param = self.codec_param;
+        for i in block_start..end {
+            let value = C::decode(&mut reader, param).unwrap();
+            if i >= start {
+                out.push(value);
+            }
+        }
+    }
+
+    /// Decompresses the entire vector into the caller's buffer, clearing it first
+    /// rather than allocating a new one. See [`into_vec`](Self::into_vec) for the
+    /// allocating equivalent.
+    pub fn into_vec_into(&self, out: &mut Vec<u64>) {
+        out.clear();
+        out.reserve(self.len);
+        let word_reader = MemWordReader::new(&self.data);
+        let mut reader = BufBitReader::<LE, MemWordReader<u64, &Vec<u64>>>::new(word_reader);
+        for _ in 0..self.len {
+            out.push(C::decode(&mut reader, self.codec_param).unwrap());
+        }
+    }
+
+    /// Retrieves the values at the given `indices`, returned in the same order as
+    /// requested.
+    ///
+    /// Indices are sorted internally so that the bitstream is swept forward once
+    /// per contiguous run of indices sharing (or adjacent within) a sample block,
+    /// reusing a single reader instead of reseeking for every index.
+    pub fn get_many(&self, indices: &[usize]) -> Vec<u64> {
+        if indices.is_empty() {
+            return Vec::new();
+        }
+
+        let mut order: Vec<usize> = (0..indices.len()).collect();
+        order.sort_by_key(|&i| indices[i]);
+
+        let mut result = vec![0u64; indices.len()];
+        let mut reader =
+            BufBitReader::<LE, MemWordReader<u64, &Vec<u64>>>::new(MemWordReader::new(&self.data));
+        let param = self.codec_param;
+        let mut decoded_through: Option<usize> = None;
+
+        for order_idx in order {
+            let index = indices[order_idx];
+            let sample_index = index / self.k;
+            let block_start = sample_index * self.k;
+
+            let resume_from = match decoded_through {
+                Some(pos) if pos < index && pos + 1 >= block_start => pos + 1,
+                _ => {
+                    reader.set_bit_pos(self.samples[sample_index] as u64).unwrap();
+                    block_start
+                }
+            };
+
+            let mut value = 0;
+            for _ in resume_from..=index {
+                value = C::decode(&mut reader, param).unwrap();
+            }
+            result[order_idx] = value;
+            decoded_through = Some(index);
+        }
+
+        result
+    }
+
+    /// Like [`decode_range`](Self::decode_range), but writes into the
+    /// caller's slice instead of allocating, for zero-allocation hot loops.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end > self.len()`, `start > end`, or `out` is shorter than
+    /// `end - start`.
+    pub fn get_range(&self, start: usize, end: usize, out: &mut [u64]) {
+        assert!(start <= end && end <= self.len, "range out of bounds");
+        assert!(out.len() >= end - start, "output buffer too small");
+        if start == end {
+            return;
+        }
+
+        let sample_index = start / self.k;
+        let block_start = sample_index * self.k;
+        let mut reader =
+            BufBitReader::<LE, MemWordReader<u64, &Vec<u64>>>::new(MemWordReader::new(&self.data));
+        reader.set_bit_pos(self.samples[sample_index] as u64).unwrap();
+
+        let param = self.codec_param;
+        let mut out_idx = 0;
+        for i in block_start..end {
+            let value = C::decode(&mut reader, param).unwrap();
+            if i >= start {
+                out[out_idx] = value;
+                out_idx += 1;
+            }
+        }
+    }
+
+    /// Like [`get_many`](Self::get_many), but writes into the caller's
+    /// slice (at each requested index's original position) instead of
+    /// allocating a result vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `indices`.
+    pub fn get_many_into(&self, indices: &[usize], out: &mut [u64]) {
+        assert!(out.len() >= indices.len(), "output buffer too small");
+        if indices.is_empty() {
+            return;
+        }
+
+        let mut order: Vec<usize> = (0..indices.len()).collect();
+        order.sort_by_key(|&i| indices[i]);
+
+        let mut reader =
+            BufBitReader::<LE, MemWordReader<u64, &Vec<u64>>>::new(MemWordReader::new(&self.data));
+        let param = self.codec_param;
+        let mut decoded_through: Option<usize> = None;
+
+        for order_idx in order {
+            let index = indices[order_idx];
+            let sample_index = index / self.k;
+            let block_start = sample_index * self.k;
+
+            let resume_from = match decoded_through {
+                Some(pos) if pos < index && pos + 1 >= block_start => pos + 1,
+                _ => {
+                    reader.set_bit_pos(self.samples[sample_index] as u64).unwrap();
+                    block_start
+                }
+            };
+
+            let mut value = 0;
+            for _ in resume_from..=index {
+                value = C::decode(&mut reader, param).unwrap();
+            }
+            out[order_idx] = value;
+            decoded_through = Some(index);
+        }
+    }
+
+    /// Like [`get_range`](Self::get_range), but appends to a caller-owned
+    /// `Vec<u64>` instead of writing into a pre-sized slice, so the same
+    /// buffer can be reused (and grown on demand) across successive
+    /// dense-range queries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.end > self.len()` or `range.start > range.end`.
+    pub fn get_range_into(&self, range: std::ops::Range<usize>, out: &mut Vec<u64>) {
+        let old_len = out.len();
+        out.resize(old_len + range.len(), 0);
+        self.get_range(range.start, range.end, &mut out[old_len..]);
+    }
 }
 
 /// Convenience constructor for codecs with no extra runtime parameter.
@@ -193,5 +193,15 @@
 //!
 //! As demonstrated, `MinimalBinaryCodec` can reduce the memory footprint dramatically, whereas
 //! `DeltaCodec` may lead to an increased size when applied to uniformly distributed data.
+pub mod ans;
+pub mod auto;
+pub mod block_store;
+pub mod codec_select;
 pub mod codecs;
+pub mod delta_transform;
+pub mod dyn_codec;
 pub mod intvec;
+pub mod mapped;
+pub mod monotone;
+pub mod streaming;
+pub mod svb;
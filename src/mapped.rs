@@ -0,0 +1,434 @@
+//! # File-backed storage over a borrowed word slice
+//!
+//! [`IntVec`](crate::intvec::IntVec) always owns its `data: Vec<u64>` limbs, so
+//! reading a serialized vector means copying the whole bitstream onto the
+//! heap before any value can be accessed. [`MappedIntVec`] is a read-only
+//! counterpart that borrows its limbs from a `&[u64]` slice instead, so a
+//! vector serialized with [`write_to`] can be reconstructed over a buffer the
+//! caller already has resident (for example one obtained by memory-mapping a
+//! file) without copying it.
+//!
+//! `IntVec` itself stays concrete over `data: Vec<u64>` rather than becoming
+//! generic over an `AsRef<[u64]>` storage parameter — that would ripple a new
+//! type parameter through every existing `IntVec<E, W, C>` call site for a
+//! benefit only the file-backed path needs. Instead, [`as_mapped`] lets an
+//! already-built `IntVec` borrow its own limbs as a `MappedIntVec`, so
+//! existing callers get the same borrowing `get`/`into_vec` path described
+//! below without a disk round-trip.
+//!
+//! > **Note:** this module depends only on `std::fs`/`std::io`; a true
+//! > zero-copy `mmap` would additionally require a crate such as `memmap2`.
+//! > [`load_file`] buffers the limb region into an owned `Vec<u64>` and hands
+//! > out a borrow of it, which keeps `MappedIntVec`'s API identical to what a
+//! > future `mmap`-backed loader would expose — swapping the buffering
+//! > strategy behind `load_file` is the only change a real `mmap` backend
+//! > would need.
+//!
+//! ## On-disk format
+//!
+//! A little-endian header followed by the sampling table and the limb data:
+//!
+//! ```text
+//! k: u64
+//! len: u64
+//! samples_len: u64
+//! samples: [u64; samples_len]       (absolute bit offsets)
+//! codec_param: [u8; size_of::<C::Params>()]   (raw bytes of the Copy param)
+//! limb_count: u64
+//! limbs: [u64; limb_count]
+//! ```
+//!
+//! ## Zero-copy over an in-memory buffer
+//!
+//! [`load_file`] is still one `read_exact` per `u64`-sized field/limb, which
+//! is fine for a `File` but wasteful once the caller already holds the whole
+//! serialized form as a `&[u8]` (for instance a `memmap2::Mmap`, which derefs
+//! to `&[u8]`). [`from_bytes`] parses the same header directly out of such a
+//! slice and reinterprets the limb region in place instead of copying it, so
+//! the only allocation left is the small `Vec<usize>` sampling table.
+//!
+//! ## Endianness
+//!
+//! [`MappedIntVec`] is declared generic over `E`/`W`/`C` exactly like
+//! [`IntVec`], with `W` a free `BitWrite<E>` parameter rather than fixed to a
+//! concrete writer — `dsi-bitstream` only implements the traits `get`/`into_vec`
+//! need (`BitSeek`, `GammaRead<E>`, ...) for the concrete `BE`/`LE` markers, not
+//! for a blanket `E: Endianness`, so those two methods live in endianness-specific
+//! impl blocks, matching how `IntVec`'s own `get`/`into_vec` live on `BEIntVec`/
+//! `LEIntVec` rather than on a generic `impl<E, W, C> IntVec<E, W, C>`.
+
+use crate::codecs::Codec;
+use crate::intvec::IntVec;
+use dsi_bitstream::prelude::*;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::marker::PhantomData;
+use std::mem;
+
+/// Borrows an already-built [`IntVec`]'s limbs as a [`MappedIntVec`], giving
+/// existing `IntVec` users the same read path a file- or buffer-backed
+/// reader gets, without writing anything to disk or copying the limbs: the
+/// returned value borrows `intvec.data` directly and clones only the small
+/// `samples` table.
+pub fn as_mapped<E, W, C>(intvec: &IntVec<E, W, C>) -> MappedIntVec<'_, E, W, C>
+where
+    E: Endianness,
+    W: BitWrite<E>,
+    C: Codec<E, W>,
+    C::Params: Copy,
+{
+    MappedIntVec {
+        data: &intvec.data,
+        samples: intvec.samples.clone(),
+        k: intvec.k,
+        len: intvec.len,
+        codec_param: intvec.codec_param,
+        _endian: PhantomData,
+        _writer: PhantomData,
+    }
+}
+
+/// Serializes `intvec` to `path` using the format documented at the module
+/// level, so it can later be reconstructed by [`load_file`] without a full
+/// in-memory `Vec<u64>` round-trip through the original codec.
+pub fn write_to<E, W, C>(intvec: &IntVec<E, W, C>, path: impl AsRef<std::path::Path>) -> io::Result<()>
+where
+    E: Endianness,
+    W: BitWrite<E>,
+    C: Codec<E, W>,
+    C::Params: Copy,
+{
+    let mut file = File::create(path)?;
+
+    file.write_all(&(intvec.k as u64).to_le_bytes())?;
+    file.write_all(&(intvec.len as u64).to_le_bytes())?;
+    file.write_all(&(intvec.samples.len() as u64).to_le_bytes())?;
+    for &sample in &intvec.samples {
+        file.write_all(&(sample as u64).to_le_bytes())?;
+    }
+
+    // SAFETY: `C::Params: Copy` rules out any `Drop` impl, and the bytes are
+    // only ever read back as the same `C::Params` on this same platform.
+    let param_bytes = unsafe {
+        std::slice::from_raw_parts(
+            &intvec.codec_param as *const C::Params as *const u8,
+            mem::size_of::<C::Params>(),
+        )
+    };
+    file.write_all(param_bytes)?;
+
+    file.write_all(&(intvec.data.len() as u64).to_le_bytes())?;
+    for &limb in &intvec.data {
+        file.write_all(&limb.to_le_bytes())?;
+    }
+
+    file.flush()
+}
+
+/// A read-only [`IntVec`]-compatible vector whose limbs are borrowed from a
+/// `&[u64]` slice rather than owned, typically produced by [`load_file`].
+pub struct MappedIntVec<'a, E: Endianness, W: BitWrite<E>, C: Codec<E, W>> {
+    data: &'a [u64],
+    samples: Vec<usize>,
+    k: usize,
+    len: usize,
+    codec_param: C::Params,
+    _endian: PhantomData<E>,
+    _writer: PhantomData<W>,
+}
+
+/// Shorthand for the writer type the crate's codecs are implemented against,
+/// matching [`block_store::BufBitWriterParam`](crate::block_store).
+type BufBitWriterParam<E> =
+    dsi_bitstream::impls::BufBitWriter<E, dsi_bitstream::impls::MemWordWriterVec<u64, Vec<u64>>>;
+
+/// Names the `BufBitWriterParam<E>` writer type for a given concrete `E`,
+/// so [`load_file`]/[`from_bytes`] can stay generic over just `E`/`C` (matching
+/// their existing call sites) instead of also asking callers to spell out a
+/// writer type: naming `BufBitWriterParam<E>` directly in a bound would force
+/// proving `BufBitWriterParam<E>: BitWrite<E>` for arbitrary `E`, which only
+/// holds for the concrete `BE`/`LE` dsi-bitstream actually implements it for.
+/// `Writer`'s own bound makes that proof an assumption instead, the same way
+/// `IntVecDecodeRange` in [`delta_transform`](crate::delta_transform) turns an
+/// unprovable direct call into an assumed bridge-trait bound.
+pub trait BufWriterFor: Endianness + Sized {
+    type Writer: BitWrite<Self>;
+}
+
+impl BufWriterFor for LE {
+    type Writer = BufBitWriterParam<LE>;
+}
+
+impl BufWriterFor for BE {
+    type Writer = BufBitWriterParam<BE>;
+}
+
+/// Return type of [`load_file`]/[`from_bytes`]: the owned or borrowed limb
+/// words alongside the header needed to [`MappedIntVecHeader::attach`] them.
+type LoadResult<Data, E, C> = io::Result<(Data, MappedIntVecHeader<E, <E as BufWriterFor>::Writer, C>)>;
+
+/// Reads back a vector written by [`write_to`], buffering its limb region
+/// into an owned `Vec<u64>` and returning it alongside the file's
+/// [`MappedIntVecHeader`].
+///
+/// The caller keeps the returned buffer alive and reattaches it with
+/// [`MappedIntVecHeader::attach`], which gives a [`MappedIntVec`] borrowing
+/// it; that is queried through `get`/`into_vec` just like an [`IntVec`],
+/// without materializing a decoded `Vec<u64>` of the original values up
+/// front.
+pub fn load_file<E, C>(
+    path: impl AsRef<std::path::Path>,
+) -> LoadResult<Vec<u64>, E, C>
+where
+    E: BufWriterFor,
+    C: Codec<E, E::Writer>,
+    C::Params: Copy,
+{
+    let mut file = File::open(path)?;
+
+    let mut u64_buf = [0u8; 8];
+    file.read_exact(&mut u64_buf)?;
+    let k = u64::from_le_bytes(u64_buf) as usize;
+
+    file.read_exact(&mut u64_buf)?;
+    let len = u64::from_le_bytes(u64_buf) as usize;
+
+    file.read_exact(&mut u64_buf)?;
+    let samples_len = u64::from_le_bytes(u64_buf) as usize;
+
+    let mut samples = Vec::with_capacity(samples_len);
+    for _ in 0..samples_len {
+        file.read_exact(&mut u64_buf)?;
+        samples.push(u64::from_le_bytes(u64_buf) as usize);
+    }
+
+    let mut param_bytes = vec![0u8; mem::size_of::<C::Params>()];
+    file.read_exact(&mut param_bytes)?;
+    // SAFETY: `write_to` wrote exactly `size_of::<C::Params>()` raw bytes of
+    // a `Copy` value produced on this same platform.
+    let codec_param = unsafe { (param_bytes.as_ptr() as *const C::Params).read_unaligned() };
+
+    file.read_exact(&mut u64_buf)?;
+    let limb_count = u64::from_le_bytes(u64_buf) as usize;
+
+    let mut data = Vec::with_capacity(limb_count);
+    for _ in 0..limb_count {
+        file.read_exact(&mut u64_buf)?;
+        data.push(u64::from_le_bytes(u64_buf));
+    }
+
+    Ok((
+        data,
+        MappedIntVecHeader {
+            samples,
+            k,
+            len,
+            codec_param,
+            _endian: PhantomData,
+            _writer: PhantomData,
+            _codec: PhantomData,
+        },
+    ))
+}
+
+/// Reads back a vector written by [`write_to`] directly out of an in-memory
+/// `&[u8]` buffer (for instance a memory-mapped file), borrowing the limb
+/// region from `bytes` instead of copying it into an owned `Vec<u64>`.
+///
+/// Returns the limb slice and the header separately, exactly like
+/// [`load_file`], so the caller reattaches them with
+/// [`MappedIntVecHeader::attach`]; the only difference is that here the
+/// limb slice borrows from `bytes` rather than from a freshly-allocated
+/// buffer.
+///
+/// # Errors
+///
+/// Returns an [`io::ErrorKind::UnexpectedEof`] error if `bytes` is too short
+/// for the header it claims to have (e.g. a truncated or corrupted file).
+///
+/// # Panics
+///
+/// Panics if `bytes` is not 8-byte aligned at the start of the limb region,
+/// since the limbs are reinterpreted in place rather than copied.
+pub fn from_bytes<E, C>(bytes: &[u8]) -> LoadResult<&[u64], E, C>
+where
+    E: BufWriterFor,
+    C: Codec<E, E::Writer>,
+    C::Params: Copy,
+{
+    fn read_u64_at(bytes: &[u8], pos: &mut usize) -> io::Result<u64> {
+        let slice = bytes
+            .get(*pos..*pos + 8)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "truncated header"))?;
+        *pos += 8;
+        Ok(u64::from_le_bytes(slice.try_into().unwrap()))
+    }
+
+    let mut pos = 0usize;
+
+    let k = read_u64_at(bytes, &mut pos)? as usize;
+    let len = read_u64_at(bytes, &mut pos)? as usize;
+    let samples_len = read_u64_at(bytes, &mut pos)? as usize;
+
+    let mut samples = Vec::with_capacity(samples_len);
+    for _ in 0..samples_len {
+        samples.push(read_u64_at(bytes, &mut pos)? as usize);
+    }
+
+    let param_size = mem::size_of::<C::Params>();
+    let param_bytes = bytes
+        .get(pos..pos + param_size)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "truncated codec param"))?;
+    // SAFETY: `write_to` wrote exactly `size_of::<C::Params>()` raw bytes of
+    // a `Copy` value produced on this same platform.
+    let codec_param = unsafe { (param_bytes.as_ptr() as *const C::Params).read_unaligned() };
+    pos += param_size;
+
+    let limb_count = read_u64_at(bytes, &mut pos)? as usize;
+    let limb_bytes = bytes
+        .get(pos..pos + limb_count * 8)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "truncated limb region"))?;
+
+    assert_eq!(
+        limb_bytes.as_ptr() as usize % mem::align_of::<u64>(),
+        0,
+        "limb region is not u64-aligned; from_bytes requires an aligned buffer (e.g. a page-aligned mmap)"
+    );
+    // SAFETY: `limb_bytes` is exactly `limb_count * size_of::<u64>()` bytes
+    // and alignment was just asserted above. `write_to` wrote the limbs as
+    // little-endian `u64`s, so the words read here equal them only on a
+    // little-endian target.
+    let data: &[u64] =
+        unsafe { std::slice::from_raw_parts(limb_bytes.as_ptr() as *const u64, limb_count) };
+
+    Ok((
+        data,
+        MappedIntVecHeader {
+            samples,
+            k,
+            len,
+            codec_param,
+            _endian: PhantomData,
+            _writer: PhantomData,
+            _codec: PhantomData,
+        },
+    ))
+}
+
+/// The metadata half of a [`load_file`] result; combine with the returned
+/// buffer via [`MappedIntVecHeader::attach`] to get a queryable
+/// [`MappedIntVec`].
+pub struct MappedIntVecHeader<E: Endianness, W: BitWrite<E>, C: Codec<E, W>> {
+    samples: Vec<usize>,
+    k: usize,
+    len: usize,
+    codec_param: C::Params,
+    _endian: PhantomData<E>,
+    _writer: PhantomData<W>,
+    _codec: PhantomData<C>,
+}
+
+impl<E: Endianness, W: BitWrite<E>, C: Codec<E, W>> MappedIntVecHeader<E, W, C> {
+    /// Borrows `data` (the buffer returned alongside this header by
+    /// [`load_file`]) to produce a queryable [`MappedIntVec`].
+    pub fn attach(self, data: &[u64]) -> MappedIntVec<'_, E, W, C> {
+        MappedIntVec {
+            data,
+            samples: self.samples,
+            k: self.k,
+            len: self.len,
+            codec_param: self.codec_param,
+            _endian: PhantomData,
+            _writer: PhantomData,
+        }
+    }
+}
+
+// `get`/`into_vec` need a real `BufBitReader<E, _>: BitRead<E> + BitSeek` plus
+// `C: Codec<E, _>` decode support, and dsi-bitstream only implements those
+// traits for the concrete `BE`/`LE` markers, not for a blanket `E: Endianness`
+// (see `delta_transform`'s `IntVecDecodeRange` for the same constraint on
+// `IntVec`). So, like `IntVec`'s own `BEIntVec`/`LEIntVec` split, the decoding
+// methods live in two endianness-specific impl blocks instead of one generic
+// one; `len`/`is_empty` don't touch the bitstream and stay generic below.
+impl<'a, C: Codec<LE, BufBitWriterParam<LE>>> MappedIntVec<'a, LE, BufBitWriterParam<LE>, C>
+where
+    C::Params: Copy,
+{
+    /// Retrieves the value at the given index. Panics if out of bounds.
+    pub fn get(&self, index: usize) -> u64 {
+        if index >= self.len {
+            panic!("Index {} is out of bounds", index);
+        }
+
+        let sample_index = index / self.k;
+        let start_bit = self.samples[sample_index];
+        let mut reader = BufBitReader::<LE, MemWordReader<u64, &[u64]>>::new(MemWordReader::new(self.data));
+        reader.set_bit_pos(start_bit as u64).unwrap();
+
+        let start_index = sample_index * self.k;
+        let mut value = 0;
+        for _ in start_index..=index {
+            value = C::decode(&mut reader, self.codec_param).unwrap();
+        }
+        value
+    }
+
+    /// Decompresses the entire vector back into an owned `Vec<u64>`.
+    pub fn into_vec(&self) -> Vec<u64> {
+        let word_reader = MemWordReader::new(self.data);
+        let mut reader = BufBitReader::<LE, MemWordReader<u64, &[u64]>>::new(word_reader);
+        let mut values = Vec::with_capacity(self.len);
+        for _ in 0..self.len {
+            values.push(C::decode(&mut reader, self.codec_param).unwrap());
+        }
+        values
+    }
+}
+
+impl<'a, C: Codec<BE, BufBitWriterParam<BE>>> MappedIntVec<'a, BE, BufBitWriterParam<BE>, C>
+where
+    C::Params: Copy,
+{
+    /// Retrieves the value at the given index. Panics if out of bounds.
+    pub fn get(&self, index: usize) -> u64 {
+        if index >= self.len {
+            panic!("Index {} is out of bounds", index);
+        }
+
+        let sample_index = index / self.k;
+        let start_bit = self.samples[sample_index];
+        let mut reader = BufBitReader::<BE, MemWordReader<u64, &[u64]>>::new(MemWordReader::new(self.data));
+        reader.set_bit_pos(start_bit as u64).unwrap();
+
+        let start_index = sample_index * self.k;
+        let mut value = 0;
+        for _ in start_index..=index {
+            value = C::decode(&mut reader, self.codec_param).unwrap();
+        }
+        value
+    }
+
+    /// Decompresses the entire vector back into an owned `Vec<u64>`.
+    pub fn into_vec(&self) -> Vec<u64> {
+        let word_reader = MemWordReader::new(self.data);
+        let mut reader = BufBitReader::<BE, MemWordReader<u64, &[u64]>>::new(word_reader);
+        let mut values = Vec::with_capacity(self.len);
+        for _ in 0..self.len {
+            values.push(C::decode(&mut reader, self.codec_param).unwrap());
+        }
+        values
+    }
+}
+
+impl<'a, E: Endianness, W: BitWrite<E>, C: Codec<E, W>> MappedIntVec<'a, E, W, C> {
+    /// Returns the number of integers stored in the vector.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
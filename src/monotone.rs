@@ -0,0 +1,177 @@
+//! # Monotone "delta-of-gaps" wrapper
+//!
+//! Sorted sequences — posting lists, cumulative offsets, timestamps — compress
+//! far better when stored as successive gaps than as raw values, since the
+//! gaps tend to stay small even when the values themselves grow large.
+//! [`MonotoneIntVec`] wraps an [`LEIntVec`] to do exactly that: on
+//! construction it stores the first element of every sampled block
+//! absolutely (reusing the existing sample array as a coarse index into the
+//! monotone sequence) and every other element as the gap from its
+//! predecessor, so `get` reconstructs a value by seeding the running total
+//! from the nearest sample and summing forward. On top of that it exposes
+//! [`successor`](MonotoneIntVec::successor)/[`predecessor`](MonotoneIntVec::predecessor),
+//! which binary-search the (monotone) sample array to find the containing
+//! block and then linearly scan it.
+//!
+//! The constructor returns an error if `input` is not non-decreasing — this
+//! wrapper only makes sense for monotone sequences.
+
+use crate::codecs::Codec;
+use crate::intvec::LEIntVec;
+use dsi_bitstream::prelude::*;
+use std::error::Error;
+
+/// A monotone (non-decreasing) integer vector stored as per-block absolute
+/// bases plus forward gaps, with `O(sampling)` random access and
+/// `successor`/`predecessor` queries.
+pub struct MonotoneIntVec<C>
+where
+    C: Codec<LE, dsi_bitstream::impls::BufBitWriter<LE, dsi_bitstream::impls::MemWordWriterVec<u64, Vec<u64>>>>,
+    C::Params: Copy,
+{
+    inner: LEIntVec<C>,
+}
+
+impl<C> MonotoneIntVec<C>
+where
+    C: Codec<LE, dsi_bitstream::impls::BufBitWriter<LE, dsi_bitstream::impls::MemWordWriterVec<u64, Vec<u64>>>, Params = ()>,
+{
+    /// Builds a [`MonotoneIntVec`] from a non-decreasing `input`, sampling
+    /// every `k`-th element.
+    ///
+    /// Returns an error if `input` is not non-decreasing.
+    pub fn from(input: &[u64], k: usize) -> Result<Self, Box<dyn Error>> {
+        if input.windows(2).any(|w| w[0] > w[1]) {
+            return Err("MonotoneIntVec requires a non-decreasing input".into());
+        }
+
+        let mut gaps = Vec::with_capacity(input.len());
+        for (i, &v) in input.iter().enumerate() {
+            if i % k == 0 {
+                gaps.push(v);
+            } else {
+                gaps.push(v - input[i - 1]);
+            }
+        }
+
+        Ok(MonotoneIntVec {
+            inner: LEIntVec::<C>::from(&gaps, k)?,
+        })
+    }
+}
+
+impl<C> MonotoneIntVec<C>
+where
+    C: Codec<LE, dsi_bitstream::impls::BufBitWriter<LE, dsi_bitstream::impls::MemWordWriterVec<u64, Vec<u64>>>>,
+    C::Params: Copy,
+{
+    /// Retrieves the value at the given index, reconstructing it by summing
+    /// forward from the nearest sampled block base. Panics if out of bounds.
+    pub fn get(&self, index: usize) -> u64 {
+        let k = self.inner.k;
+        let block_start = (index / k) * k;
+        self.inner.decode_range(block_start, index + 1).into_iter().sum()
+    }
+
+    /// Returns the number of integers stored in the vector.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the vector holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Reconstructs the full, prefix-summed values of block `block` (the
+    /// elements covered by `samples[block]`).
+    fn reconstruct_block(&self, block: usize) -> Vec<u64> {
+        let k = self.inner.k;
+        let start = block * k;
+        let end = ((block + 1) * k).min(self.inner.len());
+
+        let mut total = 0u64;
+        self.inner
+            .decode_range(start, end)
+            .into_iter()
+            .map(|gap_or_base| {
+                total += gap_or_base;
+                total
+            })
+            .collect()
+    }
+
+    /// Returns the smallest stored value `>= x`, or `None` if every stored
+    /// value is smaller than `x`.
+    pub fn successor(&self, x: u64) -> Option<u64> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let k = self.inner.k;
+        let num_samples = self.inner.samples.len();
+
+        let mut lo = 0usize;
+        let mut hi = num_samples;
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if self.inner.get(mid * k) < x {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+
+        let mut block = lo.saturating_sub(1);
+        loop {
+            for v in self.reconstruct_block(block) {
+                if v >= x {
+                    return Some(v);
+                }
+            }
+            block += 1;
+            if block >= num_samples {
+                return None;
+            }
+        }
+    }
+
+    /// Returns the largest stored value `<= x`, or `None` if every stored
+    /// value is larger than `x`.
+    pub fn predecessor(&self, x: u64) -> Option<u64> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let k = self.inner.k;
+        let num_samples = self.inner.samples.len();
+
+        let mut lo = 0usize;
+        let mut hi = num_samples;
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if self.inner.get(mid * k) <= x {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        if lo == 0 {
+            return None;
+        }
+
+        let mut block = lo - 1;
+        let mut result = None;
+        loop {
+            for v in self.reconstruct_block(block) {
+                if v <= x {
+                    result = Some(v);
+                } else {
+                    return result;
+                }
+            }
+            block += 1;
+            if block >= num_samples {
+                return result;
+            }
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! # Grouped Stream VByte block codec
+//!
+//! This module implements the original [Stream VByte](https://arxiv.org/abs/1709.08990)
+//! layout: values are packed in groups of four, with the four length tags for
+//! a group stored together in one control byte ahead of the data bytes. This
+//! is what lets a SIMD decoder load the whole group's tags with a single byte
+//! read and shuffle all four values out in one step.
+//!
+//! ## Why this isn't a [`Codec`](crate::codecs::Codec)
+//!
+//! [`Codec`](crate::codecs::Codec) encodes and decodes one value at a time,
+//! which is what lets [`IntVec`](crate::intvec::IntVec) interleave encoding
+//! with sample bookkeeping in `from_with_param`. The grouped Stream VByte
+//! layout instead needs to see four values at once to emit their shared
+//! control byte, so it cannot be expressed as a per-value `Codec` impl —
+//! [`codecs::StreamVByteCodec`](crate::codecs::StreamVByteCodec) already
+//! works around this by giving every value its own one-byte tag instead of
+//! sharing one across a group of four. This module provides the true grouped
+//! layout as standalone block primitives — `encode_block`/`decode_block` —
+//! the same way [`ans`](crate::ans) provides block primitives for rANS;
+//! wiring either into `IntVec` is future work once it grows a block-oriented
+//! construction path.
+//!
+//! ## Format
+//!
+//! Values are processed four at a time. Each group emits one control byte
+//! holding four 2-bit tags (value `i`'s tag occupies bits `2*i..2*i+2`),
+//! followed by the data bytes for all four values back to back. A tag of
+//! `0, 1, 2, 3` means the value is stored in `1, 2, 4, 8` little-endian
+//! bytes respectively — the minimum power-of-two byte width that holds it,
+//! with `0` itself stored as a single zero byte. A final partial group of
+//! 1-3 values still emits one control byte (its unused tags are left as
+//! zero padding) but only the data bytes for the values actually present.
+//!
+//! ## SIMD decode
+//!
+//! [`decode_block`] spreads each full group's bytes with a single shuffle
+//! per tag-pair on `x86_64` (`_mm_shuffle_epi8`, gated on a runtime SSSE3
+//! check) and `aarch64` (`vqtbl1q_u8`, always available), via the `simd`
+//! submodule; other targets and any trailing partial group fall back to a
+//! scalar byte copy. See the `simd` submodule's docs for why pairs rather
+//! than whole groups are the SIMD unit here.
+
+use std::error::Error;
+use std::fmt;
+
+/// Number of values packed per control byte.
+const GROUP_SIZE: usize = 4;
+
+#[derive(Debug)]
+pub struct DecodeError(String);
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stream vbyte group decode error: {}", self.0)
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Byte width encoded by a 2-bit tag: `0, 1, 2, 3` map to `1, 2, 4, 8` bytes.
+#[inline(always)]
+fn width_for_tag(tag: u8) -> usize {
+    match tag {
+        0 => 1,
+        1 => 2,
+        2 => 4,
+        _ => 8,
+    }
+}
+
+/// The 2-bit tag for the minimum of `1, 2, 4, 8` little-endian bytes that can
+/// hold `value`.
+#[inline(always)]
+fn tag_for_value(value: u64) -> u8 {
+    if value <= 0xFF {
+        0
+    } else if value <= 0xFFFF {
+        1
+    } else if value <= 0xFFFF_FFFF {
+        2
+    } else {
+        3
+    }
+}
+
+/// Encodes `values` using the grouped Stream VByte layout described in the
+/// module docs, returning the packed byte stream.
+pub fn encode_block(values: &[u64]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(values.len() + values.len() / 2);
+
+    for group in values.chunks(GROUP_SIZE) {
+        let mut control = 0u8;
+        for (i, &value) in group.iter().enumerate() {
+            control |= tag_for_value(value) << (2 * i);
+        }
+        out.push(control);
+
+        for &value in group {
+            let tag = tag_for_value(value);
+            let width = width_for_tag(tag);
+            out.extend_from_slice(&value.to_le_bytes()[..width]);
+        }
+    }
+
+    out
+}
+
+/// Decodes `n` values packed by [`encode_block`].
+///
+/// Full groups of four are spread with [`simd::decode_pair`] (two pairs per
+/// group), which on `x86_64`/`aarch64` resolves to a single table shuffle per
+/// pair instead of a byte-by-byte copy loop; a scalar fallback covers other
+/// targets and any run whose SIMD precondition isn't met. A trailing partial
+/// group (1-3 values) is always decoded scalar, since a pair needs both its
+/// values present.
+pub fn decode_block(bytes: &[u8], n: usize) -> Result<Vec<u64>, DecodeError> {
+    let mut out = Vec::with_capacity(n);
+    let mut pos = 0usize;
+    let mut remaining = n;
+
+    while remaining > 0 {
+        let control = *bytes
+            .get(pos)
+            .ok_or_else(|| DecodeError("truncated stream: missing control byte".into()))?;
+        pos += 1;
+
+        let group_len = remaining.min(GROUP_SIZE);
+        if group_len == GROUP_SIZE {
+            let tags = [
+                control & 0b11,
+                (control >> 2) & 0b11,
+                (control >> 4) & 0b11,
+                (control >> 6) & 0b11,
+            ];
+            let group_bytes: usize = tags.iter().map(|&t| width_for_tag(t)).sum();
+            let group = bytes
+                .get(pos..pos + group_bytes)
+                .ok_or_else(|| DecodeError("truncated stream: missing data bytes".into()))?;
+
+            let w0 = width_for_tag(tags[0]);
+            let w1 = width_for_tag(tags[1]);
+            let (v0, v1) = simd::decode_pair(&group[..w0 + w1], tags[0], tags[1]);
+            let (v2, v3) = simd::decode_pair(&group[w0 + w1..], tags[2], tags[3]);
+            out.extend_from_slice(&[v0, v1, v2, v3]);
+            pos += group_bytes;
+        } else {
+            for i in 0..group_len {
+                let tag = (control >> (2 * i)) & 0b11;
+                let width = width_for_tag(tag);
+                let end = pos + width;
+                let data = bytes
+                    .get(pos..end)
+                    .ok_or_else(|| DecodeError("truncated stream: missing data bytes".into()))?;
+
+                let mut le_bytes = [0u8; 8];
+                le_bytes[..width].copy_from_slice(data);
+                out.push(u64::from_le_bytes(le_bytes));
+                pos = end;
+            }
+        }
+        remaining -= group_len;
+    }
+
+    Ok(out)
+}
+
+/// SIMD-accelerated decode of one tag-pair within a group, with a scalar
+/// fallback.
+///
+/// The original [Stream VByte](https://arxiv.org/abs/1709.08990) format
+/// decodes a whole group of four in one 128-bit shuffle because its widths
+/// (1-4 bytes) always fit two values per 8-byte lane pair; here widths go up
+/// to 8 bytes, so a full group of four (up to 32 bytes) no longer fits one
+/// SSE/NEON register. Splitting the group into two *pairs* keeps each
+/// pair's worst case (two 8-byte values) at exactly 16 bytes, so the
+/// technique still applies: one 256-entry-equivalent (here 16-entry, since
+/// only 2 bits x 2 values select it) precomputed shuffle mask spreads both
+/// values' bytes into lane position in a single shuffle, zero-filling the
+/// unused high bytes of each 8-byte lane via the shuffle instructions'
+/// high-bit-clears-to-zero convention.
+mod simd {
+    /// Byte width encoded by a 2-bit tag: `0, 1, 2, 3` map to `1, 2, 4, 8`.
+    #[inline(always)]
+    fn width(tag: u8) -> usize {
+        super::width_for_tag(tag)
+    }
+
+    /// The pshufb/tbl-style control mask for a given `(tag0, tag1)` pair:
+    /// `mask[i]` is the source byte offset (within the pair's packed data)
+    /// to place at destination byte `i`, or `0x80` to zero-fill.
+    fn build_mask(tag0: u8, tag1: u8) -> [u8; 16] {
+        let w0 = width(tag0);
+        let w1 = width(tag1);
+        let mut mask = [0x80u8; 16];
+        for (i, m) in mask.iter_mut().enumerate().take(8) {
+            if i < w0 {
+                *m = i as u8;
+            }
+        }
+        for (i, m) in mask.iter_mut().enumerate().skip(8) {
+            let j = i - 8;
+            if j < w1 {
+                *m = (w0 + j) as u8;
+            }
+        }
+        mask
+    }
+
+    /// Precomputed masks for all 16 `(tag0, tag1)` combinations, indexed by
+    /// `tag0 << 2 | tag1`.
+    fn mask_table() -> &'static [[u8; 16]; 16] {
+        use std::sync::OnceLock;
+        static TABLE: OnceLock<[[u8; 16]; 16]> = OnceLock::new();
+        TABLE.get_or_init(|| {
+            let mut table = [[0u8; 16]; 16];
+            for (t0, row) in table.iter_mut().enumerate() {
+                *row = build_mask((t0 >> 2) as u8, (t0 & 0b11) as u8);
+            }
+            table
+        })
+    }
+
+    /// Decodes the two values packed in `data` under tags `tag0`/`tag1`.
+    /// `data` must hold exactly `width(tag0) + width(tag1)` bytes.
+    #[inline]
+    pub(super) fn decode_pair(data: &[u8], tag0: u8, tag1: u8) -> (u64, u64) {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("ssse3") {
+                // SAFETY: guarded by the runtime feature check above.
+                return unsafe { decode_pair_ssse3(data, tag0, tag1) };
+            }
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            // SAFETY: NEON is a baseline aarch64 feature.
+            return unsafe { decode_pair_neon(data, tag0, tag1) };
+        }
+        #[allow(unreachable_code)]
+        decode_pair_scalar(data, tag0, tag1)
+    }
+
+    /// Byte-at-a-time reference implementation, used on targets without a
+    /// SIMD path and on `x86_64` CPUs without SSSE3.
+    fn decode_pair_scalar(data: &[u8], tag0: u8, tag1: u8) -> (u64, u64) {
+        let w0 = width(tag0);
+        let mut b0 = [0u8; 8];
+        b0[..w0].copy_from_slice(&data[..w0]);
+        let mut b1 = [0u8; 8];
+        b1[..data.len() - w0].copy_from_slice(&data[w0..]);
+        (u64::from_le_bytes(b0), u64::from_le_bytes(b1))
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "ssse3")]
+    unsafe fn decode_pair_ssse3(data: &[u8], tag0: u8, tag1: u8) -> (u64, u64) {
+        use std::arch::x86_64::{__m128i, _mm_loadu_si128, _mm_shuffle_epi8, _mm_storeu_si128};
+
+        let mut padded = [0u8; 16];
+        padded[..data.len()].copy_from_slice(data);
+        let mask = mask_table()[((tag0 << 2) | tag1) as usize];
+
+        let input = _mm_loadu_si128(padded.as_ptr() as *const __m128i);
+        let shuffle = _mm_loadu_si128(mask.as_ptr() as *const __m128i);
+        let spread = _mm_shuffle_epi8(input, shuffle);
+
+        let mut out = [0u8; 16];
+        _mm_storeu_si128(out.as_mut_ptr() as *mut __m128i, spread);
+        (
+            u64::from_le_bytes(out[..8].try_into().unwrap()),
+            u64::from_le_bytes(out[8..].try_into().unwrap()),
+        )
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    unsafe fn decode_pair_neon(data: &[u8], tag0: u8, tag1: u8) -> (u64, u64) {
+        use std::arch::aarch64::{uint8x16_t, vld1q_u8, vqtbl1q_u8};
+
+        let mut padded = [0u8; 16];
+        padded[..data.len()].copy_from_slice(data);
+        let mask = mask_table()[((tag0 << 2) | tag1) as usize];
+
+        let input: uint8x16_t = vld1q_u8(padded.as_ptr());
+        let idx: uint8x16_t = vld1q_u8(mask.as_ptr());
+        let spread = vqtbl1q_u8(input, idx);
+
+        let mut out = [0u8; 16];
+        std::arch::aarch64::vst1q_u8(out.as_mut_ptr(), spread);
+        (
+            u64::from_le_bytes(out[..8].try_into().unwrap()),
+            u64::from_le_bytes(out[8..].try_into().unwrap()),
+        )
+    }
+}
@@ -0,0 +1,23 @@
+use compressed_intvec::ans::{decode_block, encode_block, AnsModel};
+
+#[test]
+fn round_trips_a_skewed_block() {
+    let alphabet_size = 8;
+    let symbols: Vec<u64> = (0..2000).map(|i| if i % 5 == 0 { 7 } else { i % 3 }).collect();
+
+    let model = AnsModel::train(&symbols, alphabet_size);
+    let (bytes, final_state) = encode_block(&symbols, &model);
+    let decoded = decode_block(&bytes, final_state, symbols.len(), &model);
+
+    assert_eq!(decoded, symbols);
+}
+
+#[test]
+fn round_trips_a_single_symbol_alphabet() {
+    let symbols = vec![3u64; 50];
+    let model = AnsModel::train(&symbols, 4);
+    let (bytes, final_state) = encode_block(&symbols, &model);
+    let decoded = decode_block(&bytes, final_state, symbols.len(), &model);
+
+    assert_eq!(decoded, symbols);
+}
@@ -0,0 +1,32 @@
+use compressed_intvec::auto::AutoIntVec;
+
+#[test]
+fn round_trips_a_skewed_distribution() {
+    let input: Vec<u64> = (0..500).map(|i| if i % 7 == 0 { 3000 } else { i % 5 }).collect();
+    let auto = AutoIntVec::from_auto(&input, 16).unwrap();
+
+    assert_eq!(auto.len(), input.len());
+    for (i, &v) in input.iter().enumerate() {
+        assert_eq!(auto.get(i), v, "mismatch at index {i}");
+    }
+    assert_eq!(auto.into_vec(), input);
+}
+
+#[test]
+fn round_trips_a_uniform_distribution() {
+    let input: Vec<u64> = (0..2000).map(|i| (i * 37) % 1000).collect();
+    let auto = AutoIntVec::from_auto(&input, 32).unwrap();
+
+    for (i, val) in auto.iter().enumerate() {
+        assert_eq!(val, input[i]);
+    }
+}
+
+#[test]
+fn samples_large_inputs_without_scanning_everything() {
+    let input: Vec<u64> = (0..200_000).map(|i| i % 64).collect();
+    let auto = AutoIntVec::from_auto(&input, 64).unwrap();
+
+    assert_eq!(auto.len(), input.len());
+    assert_eq!(auto.get(199_999), input[199_999]);
+}
@@ -0,0 +1,113 @@
+use compressed_intvec::codec_select::{select_best_codec, CodecChoice};
+use compressed_intvec::codecs::{Codec, DeltaCodec, ExpGolombCodec, GammaCodec, RiceCodec, ZetaCodec};
+use compressed_intvec::intvec::LEIntVec;
+use dsi_bitstream::impls::{BufBitWriter, MemWordWriterVec};
+use dsi_bitstream::traits::LE;
+
+type Writer = BufBitWriter<LE, MemWordWriterVec<u64, Vec<u64>>>;
+
+/// Recomputes the same brute-force comparison `select_best_codec` does,
+/// directly against the public `Codec::bit_len` API, as an independent
+/// check that its internal parameter sweep actually finds the minimum.
+fn brute_force_best(samples: &[u64]) -> CodecChoice {
+    let max_param = samples.iter().copied().max().map_or(0, |m| 64 - m.leading_zeros() as usize);
+
+    let total = |bits: &dyn Fn(u64) -> usize| -> usize { samples.iter().map(|&v| bits(v)).sum() };
+
+    let gamma = total(&|v| <GammaCodec as Codec<LE, Writer>>::bit_len(v, ()));
+    let delta = total(&|v| <DeltaCodec as Codec<LE, Writer>>::bit_len(v, ()));
+    let (exp_golomb_k, exp_golomb) = (0..=max_param)
+        .map(|k| (k, total(&|v| <ExpGolombCodec as Codec<LE, Writer>>::bit_len(v, k))))
+        .min_by_key(|&(_, b)| b)
+        .unwrap_or((0, 0));
+    let (rice_k, rice) = (0..=max_param)
+        .map(|k| (k, total(&|v| <RiceCodec as Codec<LE, Writer>>::bit_len(v, k))))
+        .min_by_key(|&(_, b)| b)
+        .unwrap_or((0, 0));
+    let (zeta_k, zeta) = (1..=max_param.max(1) as u64)
+        .map(|k| (k, total(&|v| <ZetaCodec as Codec<LE, Writer>>::bit_len(v, k))))
+        .min_by_key(|&(_, b)| b)
+        .unwrap_or((1, 0));
+
+    let smallest = gamma.min(delta).min(exp_golomb).min(rice).min(zeta);
+    if smallest == gamma {
+        CodecChoice::Gamma
+    } else if smallest == delta {
+        CodecChoice::Delta
+    } else if smallest == exp_golomb {
+        CodecChoice::ExpGolomb(exp_golomb_k)
+    } else if smallest == rice {
+        CodecChoice::Rice(rice_k)
+    } else {
+        CodecChoice::Zeta(zeta_k)
+    }
+}
+
+/// Builds the chosen codec's `LEIntVec` and checks it round-trips `input`,
+/// so `select_best_codec`'s output is exercised as a real parameter to
+/// instantiate a vector with, not just compared in isolation.
+fn assert_round_trips_with_choice(input: &[u64], choice: CodecChoice) {
+    match choice {
+        CodecChoice::Gamma => {
+            let v = LEIntVec::<GammaCodec>::from(input, 8).unwrap();
+            for (i, &x) in input.iter().enumerate() {
+                assert_eq!(v.get(i), x, "mismatch at index {i}");
+            }
+        }
+        CodecChoice::Delta => {
+            let v = LEIntVec::<DeltaCodec>::from(input, 8).unwrap();
+            for (i, &x) in input.iter().enumerate() {
+                assert_eq!(v.get(i), x, "mismatch at index {i}");
+            }
+        }
+        CodecChoice::ExpGolomb(k) => {
+            let v = LEIntVec::<ExpGolombCodec>::from_with_param(input, 8, k).unwrap();
+            for (i, &x) in input.iter().enumerate() {
+                assert_eq!(v.get(i), x, "mismatch at index {i}");
+            }
+        }
+        CodecChoice::Rice(log2_b) => {
+            let v = LEIntVec::<RiceCodec>::from_with_param(input, 8, log2_b).unwrap();
+            for (i, &x) in input.iter().enumerate() {
+                assert_eq!(v.get(i), x, "mismatch at index {i}");
+            }
+        }
+        CodecChoice::Zeta(k) => {
+            let v = LEIntVec::<ZetaCodec>::from_with_param(input, 8, k).unwrap();
+            for (i, &x) in input.iter().enumerate() {
+                assert_eq!(v.get(i), x, "mismatch at index {i}");
+            }
+        }
+    }
+}
+
+#[test]
+fn picks_a_codec_that_round_trips_a_skewed_distribution() {
+    let input: Vec<u64> = (0..500).map(|i| if i % 7 == 0 { 3000 } else { i % 5 }).collect();
+    let choice = select_best_codec(&input);
+    assert_round_trips_with_choice(&input, choice);
+}
+
+#[test]
+fn picks_a_codec_that_round_trips_a_uniform_distribution() {
+    let input: Vec<u64> = (0..2000).map(|i| (i * 37) % 1000).collect();
+    let choice = select_best_codec(&input);
+    assert_round_trips_with_choice(&input, choice);
+}
+
+#[test]
+fn matches_a_brute_force_comparison_for_small_values() {
+    let input: Vec<u64> = vec![0, 1, 1, 2, 0, 1, 3, 1, 0, 2];
+    assert_eq!(select_best_codec(&input), brute_force_best(&input));
+}
+
+#[test]
+fn matches_a_brute_force_comparison_for_large_sporadic_values() {
+    let input: Vec<u64> = (0..200).map(|i| if i % 11 == 0 { 500_000 } else { i % 3 }).collect();
+    assert_eq!(select_best_codec(&input), brute_force_best(&input));
+}
+
+#[test]
+fn empty_sample_defaults_to_gamma() {
+    assert_eq!(select_best_codec(&[]), CodecChoice::Gamma);
+}
@@ -37,8 +37,8 @@ mod tests {
 
     /// Helper for testing BE codecs.
     ///
-    /// Since `BEIntVec` does not provide a `get` method, this function validates
-    /// internal fields like `len`, `k`, and the computed sample positions.
+    /// Constructs a `BEIntVec` with the given codec and codec parameter,
+    /// then verifies that every index returns the original value.
     fn test_codec_be<C>(input: &[u64], k: usize, codec_param: C::Params)
     where
         C: Codec<BE, BufBitWriter<BE, MemWordWriterVec<u64, Vec<u64>>>>,
@@ -46,13 +46,9 @@ mod tests {
     {
         let vec_be = BEIntVec::<C>::from_with_param(input, k, codec_param.clone()).unwrap();
         assert_eq!(vec_be.len(), input.len());
-        assert_eq!(vec_be.get_sampling_rate(), k);
-        let expected_samples = if input.is_empty() {
-            0
-        } else {
-            (input.len() + k - 1) / k
-        };
-        assert_eq!(vec_be.get_samples().len(), expected_samples);
+        for (i, &val) in input.iter().enumerate() {
+            assert_eq!(vec_be.get(i), val, "Mismatch at index {}", i);
+        }
     }
 
     // --- GammaCodec Tests ---
@@ -129,6 +125,187 @@ mod tests {
         }
     }
 
+    // --- HuffmanCodec Tests ---
+    mod huffman_tests {
+        use compressed_intvec::codecs::HuffmanCodec;
+
+        use super::*;
+        #[test]
+        fn le() {
+            let input = generate_random_vec(100);
+            let k = 2;
+            let lengths = HuffmanCodec::train(&input);
+            test_codec_le::<HuffmanCodec>(&input, k, lengths);
+        }
+
+        #[test]
+        fn be() {
+            let input = generate_random_vec(100);
+            let k = 2;
+            let lengths = HuffmanCodec::train(&input);
+            test_codec_be::<HuffmanCodec>(&input, k, lengths);
+        }
+
+        #[test]
+        fn skewed_distribution() {
+            let input: Vec<u64> =
+                (0..200).map(|i| if i % 10 == 0 { 1_000_000 } else { i % 4 }).collect();
+            let k = 4;
+            let lengths = HuffmanCodec::train(&input);
+            test_codec_le::<HuffmanCodec>(&input, k, lengths);
+        }
+
+        #[test]
+        fn top_bit_set_values() {
+            let input: Vec<u64> =
+                vec![0, 1, u64::MAX, 1u64 << 63, (1u64 << 63) + 1, u64::MAX - 1, 42];
+            let k = 3;
+            let lengths = HuffmanCodec::train(&input);
+            test_codec_le::<HuffmanCodec>(&input, k, lengths);
+            test_codec_be::<HuffmanCodec>(&input, k, lengths);
+        }
+
+        #[test]
+        fn serialized_table_round_trips_and_decodes_identically() {
+            let input: Vec<u64> =
+                (0..200).map(|i| if i % 10 == 0 { 1_000_000 } else { i % 4 }).collect();
+            let lengths = HuffmanCodec::train(&input);
+
+            let bytes = HuffmanCodec::serialize_table(&lengths);
+            let restored = HuffmanCodec::deserialize_table(&bytes).unwrap();
+            assert_eq!(lengths, restored);
+
+            let k = 4;
+            test_codec_le::<HuffmanCodec>(&input, k, restored);
+        }
+
+        #[test]
+        fn serialized_table_is_compact_for_a_sparse_alphabet() {
+            let input: Vec<u64> = vec![0, 1, 1, 1, 1, 2, 2];
+            let lengths = HuffmanCodec::train(&input);
+            let bytes = HuffmanCodec::serialize_table(&lengths);
+            assert!(bytes.len() < std::mem::size_of_val(&lengths));
+        }
+
+        #[test]
+        fn deserialize_table_rejects_truncated_input() {
+            assert!(HuffmanCodec::deserialize_table(&[]).is_err());
+            assert!(HuffmanCodec::deserialize_table(&[3, 1]).is_err());
+        }
+
+        #[test]
+        fn deserialize_table_rejects_out_of_range_bucket() {
+            // max_len = 1, one codeword of length 1, whose symbol byte (200)
+            // is not a valid bucket index.
+            assert!(HuffmanCodec::deserialize_table(&[1, 1, 200]).is_err());
+        }
+    }
+
+    // --- VarIntCodec (LEB128) Tests ---
+    mod varint_tests {
+        use compressed_intvec::codecs::VarIntCodec;
+        use compressed_intvec::intvec::BEIntVec;
+
+        use super::*;
+
+        #[test]
+        fn le() {
+            let input = generate_random_vec(100);
+            let k = 2;
+            test_codec_le::<VarIntCodec>(&input, k, ());
+        }
+
+        #[test]
+        fn be() {
+            let input = generate_random_vec(100);
+            let k = 2;
+            test_codec_be::<VarIntCodec>(&input, k, ());
+        }
+
+        #[test]
+        fn empty_input() {
+            let input: Vec<u64> = vec![];
+            let vec_le = LEIntVec::<VarIntCodec>::from_with_param(&input, 3, ()).unwrap();
+            assert_eq!(vec_le.len(), 0);
+        }
+
+        #[test]
+        fn single_element() {
+            let input = vec![42];
+            test_codec_le::<VarIntCodec>(&input, 3, ());
+        }
+
+        #[test]
+        fn large_gaps() {
+            let input: Vec<u64> = (0..100).map(|x| x * 100).collect();
+            test_codec_le::<VarIntCodec>(&input, 4, ());
+        }
+
+        #[test]
+        fn large_values() {
+            let input: Vec<u64> = vec![0, 1, u32::MAX as u64, u64::MAX, u64::MAX - 1];
+            test_codec_le::<VarIntCodec>(&input, 2, ());
+            let vec_be = BEIntVec::<VarIntCodec>::from_with_param(&input, 2, ()).unwrap();
+            assert_eq!(vec_be.get(3), u64::MAX);
+        }
+
+        /// Encodes `value` with [`VarIntCodec`] and returns the raw bytes
+        /// written, so they can be checked against the canonical LEB128
+        /// encoding byte-for-byte (not just round-tripped).
+        fn encode_to_bytes(value: u64) -> Vec<u8> {
+            let word_writer = MemWordWriterVec::new(Vec::new());
+            let mut writer = BufBitWriter::<LE, MemWordWriterVec<u64, Vec<u64>>>::new(word_writer);
+            let bits = VarIntCodec::encode(&mut writer, value, ()).unwrap();
+            writer.flush().unwrap();
+            let words = writer.into_inner().unwrap().into_inner();
+
+            let mut bytes = Vec::new();
+            for word in words {
+                bytes.extend_from_slice(&word.to_le_bytes());
+            }
+            bytes.truncate(bits / 8);
+            bytes
+        }
+
+        // Cross-checks against the textbook LEB128 examples (e.g. from the
+        // Wikipedia/DWARF spec write-ups) to confirm this is byte-compatible
+        // with other LEB128 implementations, not just an internally
+        // consistent roundtrip.
+        #[test]
+        fn matches_canonical_leb128_byte_encoding() {
+            assert_eq!(encode_to_bytes(0), vec![0x00]);
+            assert_eq!(encode_to_bytes(1), vec![0x01]);
+            assert_eq!(encode_to_bytes(127), vec![0x7f]);
+            assert_eq!(encode_to_bytes(128), vec![0x80, 0x01]);
+            assert_eq!(encode_to_bytes(300), vec![0xac, 0x02]);
+            assert_eq!(encode_to_bytes(16384), vec![0x80, 0x80, 0x01]);
+        }
+    }
+
+    mod leb128_tests {
+        use compressed_intvec::codecs::Leb128Codec;
+
+        use super::*;
+
+        #[test]
+        fn le() {
+            let input = generate_random_vec(100);
+            test_codec_le::<Leb128Codec>(&input, 2, ());
+        }
+
+        #[test]
+        fn be() {
+            let input = generate_random_vec(100);
+            test_codec_be::<Leb128Codec>(&input, 2, ());
+        }
+
+        #[test]
+        fn large_values() {
+            let input: Vec<u64> = vec![0, 1, u32::MAX as u64, u64::MAX, u64::MAX - 1];
+            test_codec_le::<Leb128Codec>(&input, 2, ());
+        }
+    }
+
     // --- ParamDeltaCodec Tests ---
     mod param_delta_tests {
         use compressed_intvec::codecs::ParamDeltaCodec;
@@ -210,12 +387,11 @@ mod tests {
             let k = 3;
             let vec_be = BEIntVec::<DeltaCodec>::from_with_param(&input, k, ()).unwrap();
             assert_eq!(vec_be.len(), 1);
-            let sample_index = 0; // since there's only one sample.
-            assert_eq!(vec_be.get_samples()[sample_index], 0);
+            assert_eq!(vec_be.get(0), 42);
         }
 
         #[test]
-        fn test_in_order_iter() {
+        fn test_in_order_iter_le() {
             let input = generate_random_vec(100);
             let k = 3;
             let vec_le = LEIntVec::<GammaCodec>::from_with_param(&input, k, ()).unwrap();
@@ -226,5 +402,372 @@ mod tests {
 
             assert_eq!(vec_le.into_vec(), input);
         }
+
+        #[test]
+        fn test_in_order_iter_be() {
+            let input = generate_random_vec(100);
+            let k = 3;
+            let vec_be = BEIntVec::<GammaCodec>::from_with_param(&input, k, ()).unwrap();
+
+            for (i, val) in vec_be.iter().enumerate() {
+                assert_eq!(val, input[i]);
+            }
+
+            assert_eq!(vec_be.into_vec(), input);
+        }
+
+        #[test]
+        fn test_get_many_le() {
+            let input = generate_random_vec(100);
+            let k = 3;
+            let vec_le = LEIntVec::<GammaCodec>::from_with_param(&input, k, ()).unwrap();
+
+            let indices = vec![42, 0, 99, 17, 17, 50, 3];
+            let values = vec_le.get_many(&indices);
+            let expected: Vec<u64> = indices.iter().map(|&i| input[i]).collect();
+            assert_eq!(values, expected);
+        }
+
+        #[test]
+        fn test_get_many_be() {
+            let input = generate_random_vec(100);
+            let k = 3;
+            let vec_be = BEIntVec::<GammaCodec>::from_with_param(&input, k, ()).unwrap();
+
+            let indices = vec![42, 0, 99, 17, 17, 50, 3];
+            let values = vec_be.get_many(&indices);
+            let expected: Vec<u64> = indices.iter().map(|&i| input[i]).collect();
+            assert_eq!(values, expected);
+        }
+
+        #[test]
+        fn test_get_range_into_buffer() {
+            let input = generate_random_vec(100);
+            let k = 3;
+            let vec_le = LEIntVec::<GammaCodec>::from_with_param(&input, k, ()).unwrap();
+            let vec_be = BEIntVec::<GammaCodec>::from_with_param(&input, k, ()).unwrap();
+
+            let mut out = vec![0u64; 20];
+            vec_le.get_range(10, 30, &mut out);
+            assert_eq!(out, input[10..30]);
+
+            vec_be.get_range(10, 30, &mut out);
+            assert_eq!(out, input[10..30]);
+        }
+
+        #[test]
+        fn test_get_many_into_buffer() {
+            let input = generate_random_vec(100);
+            let k = 3;
+            let vec_le = LEIntVec::<GammaCodec>::from_with_param(&input, k, ()).unwrap();
+            let vec_be = BEIntVec::<GammaCodec>::from_with_param(&input, k, ()).unwrap();
+
+            let indices = vec![42, 0, 99, 17, 17, 50, 3];
+            let expected: Vec<u64> = indices.iter().map(|&i| input[i]).collect();
+
+            let mut out = vec![0u64; indices.len()];
+            vec_le.get_many_into(&indices, &mut out);
+            assert_eq!(out, expected);
+
+            vec_be.get_many_into(&indices, &mut out);
+            assert_eq!(out, expected);
+        }
+
+        #[test]
+        fn test_get_range_into_appends_to_reused_buffer() {
+            let input = generate_random_vec(100);
+            let k = 3;
+            let vec_le = LEIntVec::<GammaCodec>::from_with_param(&input, k, ()).unwrap();
+            let vec_be = BEIntVec::<GammaCodec>::from_with_param(&input, k, ()).unwrap();
+
+            let mut out = Vec::new();
+            vec_le.get_range_into(10..30, &mut out);
+            assert_eq!(out, input[10..30]);
+
+            // A second call appends rather than overwriting, so the same
+            // buffer can be reused across successive dense-range queries.
+            vec_be.get_range_into(30..40, &mut out);
+            assert_eq!(out[..20], input[10..30]);
+            assert_eq!(out[20..], input[30..40]);
+        }
+    }
+
+    // --- Codec::bit_len Tests ---
+    //
+    // `encode` already returns the number of bits it wrote, so `bit_len` is
+    // cross-checked directly against that return value rather than against
+    // an independently-derived formula.
+    mod bit_len_tests {
+        use compressed_intvec::codecs::{
+            CompactCodec, HuffmanCodec, Leb128Codec, MinimalBinaryCodec, ParamDeltaCodec,
+            ParamGammaCodec, ParamZetaCodec, RiceCodec, StreamVByteCodec, VarIntCodec, ZetaCodec,
+        };
+
+        use super::*;
+
+        /// Encodes `value` into a scratch `LE` bitstream and asserts the
+        /// bits `encode` reports match `C::bit_len`.
+        fn assert_bit_len_matches_encode<C>(value: u64, params: C::Params)
+        where
+            C: Codec<LE, BufBitWriter<LE, MemWordWriterVec<u64, Vec<u64>>>>,
+            C::Params: Copy,
+        {
+            let word_writer = MemWordWriterVec::new(Vec::new());
+            let mut writer = BufBitWriter::<LE, MemWordWriterVec<u64, Vec<u64>>>::new(word_writer);
+            let written = C::encode(&mut writer, value, params).unwrap();
+            assert_eq!(
+                C::bit_len(value, params),
+                written,
+                "bit_len mismatch for value {value}"
+            );
+        }
+
+        #[test]
+        fn gamma() {
+            for &v in &[0, 1, 2, 100, 1_000_000, u64::MAX] {
+                assert_bit_len_matches_encode::<GammaCodec>(v, ());
+            }
+        }
+
+        #[test]
+        fn delta() {
+            for &v in &[0, 1, 2, 100, 1_000_000, u64::MAX] {
+                assert_bit_len_matches_encode::<DeltaCodec>(v, ());
+            }
+        }
+
+        #[test]
+        fn exp_golomb() {
+            for k in [0, 1, 2, 5] {
+                for &v in &[0, 1, 2, 100, 1_000_000, u64::MAX] {
+                    assert_bit_len_matches_encode::<ExpGolombCodec>(v, k);
+                }
+            }
+        }
+
+        #[test]
+        fn zeta() {
+            for k in [1, 2, 3, 5] {
+                for &v in &[0, 1, 2, 100, 1_000_000, u64::MAX] {
+                    assert_bit_len_matches_encode::<ZetaCodec>(v, k);
+                }
+            }
+        }
+
+        #[test]
+        fn rice() {
+            for log2_b in [0, 1, 3, 8] {
+                for &v in &[0, 1, 2, 100, 1_000_000] {
+                    assert_bit_len_matches_encode::<RiceCodec>(v, log2_b);
+                }
+            }
+        }
+
+        #[test]
+        fn minimal_binary() {
+            for upper_bound in [1, 7, 8, 1000, u32::MAX as u64] {
+                for v in [0, upper_bound / 2, upper_bound - 1] {
+                    assert_bit_len_matches_encode::<MinimalBinaryCodec>(v, upper_bound);
+                }
+            }
+        }
+
+        #[test]
+        fn param_zeta() {
+            for &v in &[0, 1, 2, 100, 1_000_000] {
+                assert_bit_len_matches_encode::<ParamZetaCodec<true>>(v, ());
+                assert_bit_len_matches_encode::<ParamZetaCodec<false>>(v, ());
+            }
+        }
+
+        #[test]
+        fn param_delta() {
+            for &v in &[0, 1, 2, 100, 1_000_000] {
+                assert_bit_len_matches_encode::<ParamDeltaCodec<true, true>>(v, ());
+                assert_bit_len_matches_encode::<ParamDeltaCodec<false, false>>(v, ());
+            }
+        }
+
+        #[test]
+        fn param_gamma() {
+            for &v in &[0, 1, 2, 100, 1_000_000] {
+                assert_bit_len_matches_encode::<ParamGammaCodec<true>>(v, ());
+                assert_bit_len_matches_encode::<ParamGammaCodec<false>>(v, ());
+            }
+        }
+
+        #[test]
+        fn compact() {
+            for &v in &[0, 63, 64, 16_383, 16_384, 1 << 29, 1 << 30, u64::MAX] {
+                assert_bit_len_matches_encode::<CompactCodec>(v, ());
+            }
+        }
+
+        #[test]
+        fn stream_vbyte() {
+            for &v in &[0, 255, 256, u32::MAX as u64, u64::MAX] {
+                assert_bit_len_matches_encode::<StreamVByteCodec>(v, ());
+            }
+        }
+
+        #[test]
+        fn varint_and_leb128() {
+            for &v in &[0, 127, 128, 16_384, u64::MAX] {
+                assert_bit_len_matches_encode::<VarIntCodec>(v, ());
+                assert_bit_len_matches_encode::<Leb128Codec>(v, ());
+            }
+        }
+
+        #[test]
+        fn huffman() {
+            let input = generate_random_vec(200);
+            let lengths = HuffmanCodec::train(&input);
+            for &v in &input {
+                assert_bit_len_matches_encode::<HuffmanCodec>(v, lengths);
+            }
+        }
+    }
+
+    mod skip_tests {
+        use compressed_intvec::codecs::{
+            CompactCodec, HuffmanCodec, Leb128Codec, MinimalBinaryCodec, ParamDeltaCodec,
+            ParamGammaCodec, ParamZetaCodec, RiceCodec, StreamVByteCodec, VarIntCodec, ZetaCodec,
+        };
+        use dsi_bitstream::impls::{BufBitReader, MemWordReader};
+
+        use super::*;
+
+        /// Encodes `value` followed by `next` into a scratch `LE` bitstream,
+        /// then checks that `C::skip` both reports the same bit count as
+        /// `encode` and leaves the reader positioned so that `next` decodes
+        /// correctly right after — i.e. it actually advances past the first
+        /// code rather than just computing its length.
+        fn assert_skip_then_decodes_next<C>(value: u64, next: u64, params: C::Params)
+        where
+            C: Codec<LE, BufBitWriter<LE, MemWordWriterVec<u64, Vec<u64>>>>,
+            C::Params: Copy,
+        {
+            let word_writer = MemWordWriterVec::new(Vec::new());
+            let mut writer = BufBitWriter::<LE, MemWordWriterVec<u64, Vec<u64>>>::new(word_writer);
+            let written = C::encode(&mut writer, value, params).unwrap();
+            C::encode(&mut writer, next, params).unwrap();
+            writer.flush().unwrap();
+            let words = writer.into_inner().unwrap().into_inner();
+
+            let word_reader = MemWordReader::new(&words);
+            let mut reader = BufBitReader::<LE, _>::new(word_reader);
+            let skipped = C::skip(&mut reader, params).unwrap();
+            assert_eq!(skipped, written, "skip bit count mismatch for value {value}");
+            assert_eq!(
+                C::decode(&mut reader, params).unwrap(),
+                next,
+                "skip left the reader misaligned for value {value}"
+            );
+        }
+
+        #[test]
+        fn gamma() {
+            for &v in &[0, 1, 2, 100, 1_000_000, u64::MAX] {
+                assert_skip_then_decodes_next::<GammaCodec>(v, 42, ());
+            }
+        }
+
+        #[test]
+        fn delta() {
+            for &v in &[0, 1, 2, 100, 1_000_000, u64::MAX] {
+                assert_skip_then_decodes_next::<DeltaCodec>(v, 42, ());
+            }
+        }
+
+        #[test]
+        fn exp_golomb() {
+            for k in [0, 1, 2, 5] {
+                for &v in &[0, 1, 2, 100, 1_000_000, u64::MAX] {
+                    assert_skip_then_decodes_next::<ExpGolombCodec>(v, 42, k);
+                }
+            }
+        }
+
+        #[test]
+        fn zeta() {
+            for k in [1, 2, 3, 5] {
+                for &v in &[0, 1, 2, 100, 1_000_000, u64::MAX] {
+                    assert_skip_then_decodes_next::<ZetaCodec>(v, 42, k);
+                }
+            }
+        }
+
+        #[test]
+        fn rice() {
+            for log2_b in [0, 1, 3, 8] {
+                for &v in &[0, 1, 2, 100, 1_000_000] {
+                    assert_skip_then_decodes_next::<RiceCodec>(v, 42, log2_b);
+                }
+            }
+        }
+
+        #[test]
+        fn minimal_binary() {
+            for upper_bound in [1, 7, 8, 1000, u32::MAX as u64] {
+                for v in [0, upper_bound / 2, upper_bound - 1] {
+                    assert_skip_then_decodes_next::<MinimalBinaryCodec>(v, upper_bound / 2, upper_bound);
+                }
+            }
+        }
+
+        #[test]
+        fn param_zeta() {
+            for &v in &[0, 1, 2, 100, 1_000_000] {
+                assert_skip_then_decodes_next::<ParamZetaCodec<true>>(v, 42, ());
+                assert_skip_then_decodes_next::<ParamZetaCodec<false>>(v, 42, ());
+            }
+        }
+
+        #[test]
+        fn param_delta() {
+            for &v in &[0, 1, 2, 100, 1_000_000] {
+                assert_skip_then_decodes_next::<ParamDeltaCodec<true, true>>(v, 42, ());
+                assert_skip_then_decodes_next::<ParamDeltaCodec<false, false>>(v, 42, ());
+            }
+        }
+
+        #[test]
+        fn param_gamma() {
+            for &v in &[0, 1, 2, 100, 1_000_000] {
+                assert_skip_then_decodes_next::<ParamGammaCodec<true>>(v, 42, ());
+                assert_skip_then_decodes_next::<ParamGammaCodec<false>>(v, 42, ());
+            }
+        }
+
+        #[test]
+        fn compact() {
+            for &v in &[0, 63, 64, 16_383, 16_384, 1 << 29, 1 << 30, u64::MAX] {
+                assert_skip_then_decodes_next::<CompactCodec>(v, 42, ());
+            }
+        }
+
+        #[test]
+        fn stream_vbyte() {
+            for &v in &[0, 255, 256, u32::MAX as u64, u64::MAX] {
+                assert_skip_then_decodes_next::<StreamVByteCodec>(v, 42, ());
+            }
+        }
+
+        #[test]
+        fn varint_and_leb128() {
+            for &v in &[0, 127, 128, 16_384, u64::MAX] {
+                assert_skip_then_decodes_next::<VarIntCodec>(v, 42, ());
+                assert_skip_then_decodes_next::<Leb128Codec>(v, 42, ());
+            }
+        }
+
+        #[test]
+        fn huffman() {
+            let input = generate_random_vec(200);
+            let lengths = HuffmanCodec::train(&input);
+            for &v in &input {
+                assert_skip_then_decodes_next::<HuffmanCodec>(v, input[0], lengths);
+            }
+        }
     }
 }
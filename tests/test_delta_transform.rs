@@ -0,0 +1,48 @@
+use compressed_intvec::codecs::GammaCodec;
+use compressed_intvec::delta_transform::{BEDeltaTransform, DeltaTransform};
+
+#[test]
+fn reconstructs_ascending_values() {
+    let input: Vec<u64> = (0..100).collect();
+    let transform = DeltaTransform::<GammaCodec>::from(&input, 8).unwrap();
+    for (i, &v) in input.iter().enumerate() {
+        assert_eq!(transform.get(i), v, "mismatch at index {i}");
+    }
+}
+
+#[test]
+fn reconstructs_descending_values() {
+    let input: Vec<u64> = (0..100).rev().collect();
+    let transform = DeltaTransform::<GammaCodec>::from(&input, 8).unwrap();
+    for (i, &v) in input.iter().enumerate() {
+        assert_eq!(transform.get(i), v, "mismatch at index {i}");
+    }
+}
+
+#[test]
+fn reconstructs_values_with_large_signed_gaps() {
+    let input: Vec<u64> = vec![1000, 5, 900, 10, 10_000, 1, 50, 7_000];
+    let transform = DeltaTransform::<GammaCodec>::from(&input, 3).unwrap();
+    for (i, &v) in input.iter().enumerate() {
+        assert_eq!(transform.get(i), v, "mismatch at index {i}");
+    }
+}
+
+#[test]
+fn single_element() {
+    let input = vec![42u64];
+    let transform = DeltaTransform::<GammaCodec>::from(&input, 4).unwrap();
+    assert_eq!(transform.get(0), 42);
+    assert_eq!(transform.len(), 1);
+    assert!(!transform.is_empty());
+}
+
+#[test]
+fn be_variant_reconstructs_values_with_large_signed_gaps() {
+    let input: Vec<u64> = vec![1000, 5, 900, 10, 10_000, 1, 50, 7_000];
+    let transform = BEDeltaTransform::<GammaCodec>::from(&input, 3).unwrap();
+    for (i, &v) in input.iter().enumerate() {
+        assert_eq!(transform.get(i), v, "mismatch at index {i}");
+    }
+    assert_eq!(transform.len(), input.len());
+}
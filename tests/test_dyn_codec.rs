@@ -0,0 +1,155 @@
+use compressed_intvec::codecs::HuffmanCodec;
+use compressed_intvec::dyn_codec::DynCodec;
+use dsi_bitstream::impls::{BufBitReader, BufBitWriter, MemWordReader, MemWordWriterVec};
+use dsi_bitstream::traits::{BitWrite, LE};
+
+/// Encodes `values` one after another with `codec` and decodes them back
+/// through a fresh reader over the same words, checking both the round
+/// trip and that `bit_len_dyn` matches the bits `encode_dyn` actually wrote.
+fn assert_round_trips(codec: DynCodec, values: &[u64]) {
+    let word_writer = MemWordWriterVec::new(Vec::<u64>::new());
+    let mut writer = BufBitWriter::<LE, _>::new(word_writer);
+
+    let mut total_bits = 0;
+    for &value in values {
+        let bits = codec.encode_dyn(&mut writer, value).unwrap();
+        assert_eq!(bits, codec.bit_len_dyn(value), "bit_len_dyn mismatch for {value}");
+        total_bits += bits;
+    }
+    writer.flush().unwrap();
+    let words = writer.into_inner().unwrap().into_inner();
+
+    let word_reader = MemWordReader::new(&words);
+    let mut reader = BufBitReader::<LE, _>::new(word_reader);
+    for &value in values {
+        assert_eq!(codec.decode_dyn(&mut reader).unwrap(), value);
+    }
+    assert!(total_bits > 0 || values.is_empty());
+}
+
+/// Round-trips `codec` through [`DynCodec::to_bytes`]/[`DynCodec::from_bytes`]
+/// and checks the consumed length matches the serialized length exactly.
+fn assert_serializes(codec: DynCodec) {
+    let bytes = codec.to_bytes().unwrap();
+    let (decoded, consumed) = DynCodec::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, codec);
+    assert_eq!(consumed, bytes.len());
+}
+
+#[test]
+fn gamma_round_trips() {
+    assert_round_trips(DynCodec::Gamma, &[0, 1, 3, 6, 8, 13, 1000]);
+}
+
+#[test]
+fn delta_round_trips() {
+    assert_round_trips(DynCodec::Delta, &[0, 1, 3, 6, 8, 13, 1000]);
+}
+
+#[test]
+fn exp_golomb_round_trips() {
+    assert_round_trips(DynCodec::ExpGolomb(4), &[0, 1, 3, 6, 8, 13, 1000]);
+}
+
+#[test]
+fn zeta_round_trips() {
+    assert_round_trips(DynCodec::Zeta(3), &[0, 1, 3, 6, 8, 13, 1000]);
+}
+
+#[test]
+fn rice_round_trips() {
+    assert_round_trips(DynCodec::Rice(2), &[0, 1, 3, 6, 8, 13, 1000]);
+}
+
+#[test]
+fn minimal_binary_round_trips() {
+    assert_round_trips(DynCodec::MinimalBinary(2000), &[0, 1, 3, 6, 8, 13, 1000]);
+}
+
+#[test]
+fn param_zeta_round_trips() {
+    assert_round_trips(DynCodec::ParamZeta { use_table: true }, &[0, 1, 3, 6, 8, 13, 1000]);
+    assert_round_trips(DynCodec::ParamZeta { use_table: false }, &[0, 1, 3, 6, 8, 13, 1000]);
+}
+
+#[test]
+fn param_delta_round_trips() {
+    for use_delta_table in [true, false] {
+        for use_gamma_table in [true, false] {
+            assert_round_trips(
+                DynCodec::ParamDelta { use_delta_table, use_gamma_table },
+                &[0, 1, 3, 6, 8, 13, 1000],
+            );
+        }
+    }
+}
+
+#[test]
+fn param_gamma_round_trips() {
+    assert_round_trips(DynCodec::ParamGamma { use_table: true }, &[0, 1, 3, 6, 8, 13, 1000]);
+    assert_round_trips(DynCodec::ParamGamma { use_table: false }, &[0, 1, 3, 6, 8, 13, 1000]);
+}
+
+#[test]
+fn compact_round_trips() {
+    assert_round_trips(DynCodec::Compact, &[0, 1, 3, 6, 8, 13, 1000]);
+}
+
+#[test]
+fn stream_vbyte_round_trips() {
+    assert_round_trips(DynCodec::StreamVByte, &[0, 1, 3, 6, 8, 13, 1000]);
+}
+
+#[test]
+fn var_int_round_trips() {
+    assert_round_trips(DynCodec::VarInt, &[0, 1, 3, 6, 8, 13, 1000]);
+}
+
+#[test]
+fn leb128_round_trips() {
+    assert_round_trips(DynCodec::Leb128, &[0, 1, 3, 6, 8, 13, 1000]);
+}
+
+#[test]
+fn huffman_round_trips() {
+    let input = [0, 1, 3, 6, 8, 13, 1000, 1, 1, 3];
+    let lengths = HuffmanCodec::train(&input);
+    assert_round_trips(DynCodec::Huffman(lengths), &input);
+}
+
+#[test]
+fn to_bytes_round_trips_every_variant() {
+    assert_serializes(DynCodec::Gamma);
+    assert_serializes(DynCodec::Delta);
+    assert_serializes(DynCodec::ExpGolomb(5));
+    assert_serializes(DynCodec::Zeta(2));
+    assert_serializes(DynCodec::Rice(3));
+    assert_serializes(DynCodec::MinimalBinary(1000));
+    assert_serializes(DynCodec::ParamZeta { use_table: true });
+    assert_serializes(DynCodec::ParamDelta { use_delta_table: false, use_gamma_table: true });
+    assert_serializes(DynCodec::ParamGamma { use_table: false });
+    assert_serializes(DynCodec::Compact);
+    assert_serializes(DynCodec::StreamVByte);
+    assert_serializes(DynCodec::VarInt);
+    assert_serializes(DynCodec::Leb128);
+
+    let lengths = HuffmanCodec::train(&[0, 1, 3, 6, 8, 13, 1000, 1, 1, 3]);
+    assert_serializes(DynCodec::Huffman(lengths));
+}
+
+#[test]
+fn from_bytes_rejects_empty_input() {
+    assert!(DynCodec::from_bytes(&[]).is_err());
+}
+
+#[test]
+fn from_bytes_rejects_unknown_tag() {
+    assert!(DynCodec::from_bytes(&[255]).is_err());
+}
+
+#[test]
+fn to_bytes_rejects_parameters_that_do_not_fit_in_a_byte() {
+    assert!(DynCodec::ExpGolomb(256).to_bytes().is_err());
+    assert!(DynCodec::Zeta(256).to_bytes().is_err());
+    assert!(DynCodec::Rice(256).to_bytes().is_err());
+}
@@ -0,0 +1,80 @@
+use compressed_intvec::codecs::GammaCodec;
+use compressed_intvec::intvec::LEIntVec;
+use compressed_intvec::mapped::{as_mapped, from_bytes, load_file, write_to};
+use dsi_bitstream::traits::LE;
+
+/// A path in the system temp dir unique to this test run, cleaned up on drop.
+struct ScratchFile(std::path::PathBuf);
+
+impl ScratchFile {
+    fn new(name: &str) -> Self {
+        let mut path = std::env::temp_dir();
+        path.push(format!("compressed-intvec-test-{name}-{:?}", std::thread::current().id()));
+        ScratchFile(path)
+    }
+}
+
+impl Drop for ScratchFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn round_trips_through_a_file() {
+    let input: Vec<u64> = (0..500).map(|i| (i * 17) % 1000).collect();
+    let vec = LEIntVec::<GammaCodec>::from(&input, 16).unwrap();
+
+    let file = ScratchFile::new("round-trip");
+    write_to(&vec, &file.0).unwrap();
+
+    let (data, header) = load_file::<LE, GammaCodec>(&file.0).unwrap();
+    let mapped = header.attach(&data);
+    assert_eq!(mapped.len(), input.len());
+    for (i, &v) in input.iter().enumerate() {
+        assert_eq!(mapped.get(i), v, "mismatch at index {i}");
+    }
+}
+
+#[test]
+fn from_bytes_matches_load_file() {
+    let input: Vec<u64> = (0..200).map(|i| (i * 31) % 700).collect();
+    let vec = LEIntVec::<GammaCodec>::from(&input, 8).unwrap();
+
+    let file = ScratchFile::new("from-bytes");
+    write_to(&vec, &file.0).unwrap();
+    let bytes = std::fs::read(&file.0).unwrap();
+
+    let (data, header) = from_bytes::<LE, GammaCodec>(&bytes).unwrap();
+    let mapped = header.attach(data);
+    assert_eq!(mapped.len(), input.len());
+    for (i, &v) in input.iter().enumerate() {
+        assert_eq!(mapped.get(i), v, "mismatch at index {i}");
+    }
+}
+
+#[test]
+fn as_mapped_borrows_an_existing_intvec() {
+    let input: Vec<u64> = (0..300).map(|i| (i * 11) % 900).collect();
+    let vec = LEIntVec::<GammaCodec>::from(&input, 8).unwrap();
+
+    let mapped = as_mapped(&vec);
+    assert_eq!(mapped.len(), input.len());
+    for (i, &v) in input.iter().enumerate() {
+        assert_eq!(mapped.get(i), v, "mismatch at index {i}");
+    }
+    assert_eq!(mapped.into_vec(), input);
+}
+
+#[test]
+fn from_bytes_rejects_truncated_input() {
+    let input: Vec<u64> = (0..50).collect();
+    let vec = LEIntVec::<GammaCodec>::from(&input, 8).unwrap();
+
+    let file = ScratchFile::new("truncated");
+    write_to(&vec, &file.0).unwrap();
+    let mut bytes = std::fs::read(&file.0).unwrap();
+    bytes.truncate(bytes.len() - 4);
+
+    assert!(from_bytes::<LE, GammaCodec>(&bytes).is_err());
+}
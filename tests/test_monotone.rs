@@ -0,0 +1,31 @@
+use compressed_intvec::codecs::GammaCodec;
+use compressed_intvec::monotone::MonotoneIntVec;
+
+#[test]
+fn reconstructs_original_values() {
+    let input: Vec<u64> = vec![1, 1, 2, 5, 5, 9, 20, 20, 21, 50];
+    let monotone = MonotoneIntVec::<GammaCodec>::from(&input, 3).unwrap();
+    for (i, &v) in input.iter().enumerate() {
+        assert_eq!(monotone.get(i), v, "mismatch at index {i}");
+    }
+}
+
+#[test]
+fn rejects_non_monotone_input() {
+    let input = vec![1, 2, 1];
+    assert!(MonotoneIntVec::<GammaCodec>::from(&input, 2).is_err());
+}
+
+#[test]
+fn successor_and_predecessor() {
+    let input: Vec<u64> = vec![1, 3, 3, 7, 10, 15, 15, 20];
+    let monotone = MonotoneIntVec::<GammaCodec>::from(&input, 3).unwrap();
+
+    assert_eq!(monotone.successor(8), Some(10));
+    assert_eq!(monotone.successor(1), Some(1));
+    assert_eq!(monotone.successor(21), None);
+
+    assert_eq!(monotone.predecessor(8), Some(7));
+    assert_eq!(monotone.predecessor(20), Some(20));
+    assert_eq!(monotone.predecessor(0), None);
+}
@@ -0,0 +1,30 @@
+use compressed_intvec::codecs::GammaCodec;
+use compressed_intvec::streaming::{open, write_from_iter};
+use dsi_bitstream::traits::LE;
+use std::io::Cursor;
+
+#[test]
+fn round_trips_through_a_cursor() {
+    let input: Vec<u64> = (0..500).map(|i| (i * 17) % 1000).collect();
+
+    let mut buf = Vec::new();
+    write_from_iter::<LE, GammaCodec>(input.iter().copied(), 16, (), &mut buf).unwrap();
+
+    let mut vec = open::<_, LE, GammaCodec>(Cursor::new(buf)).unwrap();
+    assert_eq!(vec.len(), input.len());
+    for (i, &v) in input.iter().enumerate() {
+        assert_eq!(vec.get(i).unwrap(), v, "mismatch at index {i}");
+    }
+}
+
+#[test]
+fn round_trips_an_empty_input() {
+    let input: Vec<u64> = Vec::new();
+
+    let mut buf = Vec::new();
+    write_from_iter::<LE, GammaCodec>(input.into_iter(), 16, (), &mut buf).unwrap();
+
+    let vec = open::<_, LE, GammaCodec>(Cursor::new(buf)).unwrap();
+    assert_eq!(vec.len(), 0);
+    assert!(vec.is_empty());
+}
@@ -0,0 +1,37 @@
+use compressed_intvec::svb::{decode_block, encode_block};
+
+#[test]
+fn round_trips_a_full_groups_block() {
+    let values: Vec<u64> = vec![0, 255, 256, 65535, 65536, 1 << 40, 9, 12];
+    let bytes = encode_block(&values);
+    let decoded = decode_block(&bytes, values.len()).unwrap();
+
+    assert_eq!(decoded, values);
+}
+
+#[test]
+fn round_trips_a_partial_final_group() {
+    let values: Vec<u64> = vec![1, 2, 3, 4, 5, 6];
+    let bytes = encode_block(&values);
+    let decoded = decode_block(&bytes, values.len()).unwrap();
+
+    assert_eq!(decoded, values);
+}
+
+#[test]
+fn round_trips_all_zero_values() {
+    let values: Vec<u64> = vec![0; 9];
+    let bytes = encode_block(&values);
+    let decoded = decode_block(&bytes, values.len()).unwrap();
+
+    assert_eq!(decoded, values);
+}
+
+#[test]
+fn decode_rejects_truncated_input() {
+    let values: Vec<u64> = vec![1, 2, 3, 4];
+    let mut bytes = encode_block(&values);
+    bytes.truncate(bytes.len() - 1);
+
+    assert!(decode_block(&bytes, values.len()).is_err());
+}